@@ -1,4 +1,4 @@
-use walrus_rs::{WalrusClient, WalrusError};
+use walrus_rs::{StoreOptions, WalrusClient, WalrusError};
 
 #[tokio::main]
 async fn main() -> Result<(), WalrusError> {
@@ -12,16 +12,17 @@ async fn main() -> Result<(), WalrusError> {
     // Example: Store a blob
     println!("Storing a blob...");
     let data = "some string from Rust SDK1".as_bytes().to_vec();
-    let store_result = client.store_blob(data, Some(1), None, None, None).await?;
+    let store_result = client
+        .store_blob(data, &StoreOptions::new().epochs(1))
+        .await?;
     println!("Blob store result: {:?}", store_result);
 
-    if let Some(newly_created) = store_result.newly_created {
-        let blob_id = newly_created.blob_object.blob_id;
-        println!("Newly created blob ID: {}", blob_id);
+    if let Some(blob_id) = store_result.blob_id() {
+        println!("Stored blob ID: {}", blob_id);
 
         // Example: Read a blob by ID
         println!("Reading blob by ID: {}", blob_id);
-        let read_data = client.read_blob_by_id(&blob_id).await?;
+        let read_data = client.read_blob_by_id(blob_id).await?;
         println!("Read blob data: {}", String::from_utf8_lossy(&read_data));
     }
 
@@ -31,15 +32,14 @@ async fn main() -> Result<(), WalrusError> {
     let file2_data = "content of file 2d".as_bytes().to_vec();
     let files = vec![("file1.txt", file1_data), ("file2.txt", file2_data)];
     let quilt_store_result = client
-        .store_quilt(files, None, Some(1), None, None, None)
+        .store_quilt(files, None, &StoreOptions::new().epochs(1))
         .await?;
     println!("Quilt store result: {:?}", quilt_store_result);
 
-    if let Some(newly_created) = quilt_store_result.blob_store_result.newly_created {
-        let quilt_id = newly_created.blob_object.blob_id;
-        println!("Newly created quilt ID: {}", quilt_id);
+    if let Some(quilt_id) = quilt_store_result.blob_store_result.blob_id() {
+        println!("Stored quilt ID: {}", quilt_id);
 
-        if let Some(stored_quilt_blob) = quilt_store_result.stored_quilt_blobs.get(0) {
+        if let Some(stored_quilt_blob) = quilt_store_result.stored_quilt_blobs.first() {
             let quilt_patch_id = &stored_quilt_blob.quilt_patch_id;
             println!("First quilt patch ID: {}", quilt_patch_id);
 
@@ -0,0 +1,91 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use walrus_rs::WalrusError;
+
+use crate::OutputFormat;
+
+/// The Aggregator used when none is configured, as in the examples.
+pub const DEFAULT_AGGREGATOR: &str = "https://aggregator.testnet.walrus.atalma.io";
+
+/// The Publisher used when none is configured, as in the examples.
+pub const DEFAULT_PUBLISHER: &str = "https://publisher.walrus-01.tududes.com";
+
+/// The settings read from the TOML configuration file.
+///
+/// Every setting is optional and overridden by the corresponding command-line flag or environment variable.
+///
+/// ```toml
+/// aggregator = "https://aggregator.testnet.walrus.atalma.io"
+/// publisher = "https://publisher.walrus-01.tududes.com"
+/// format = "json"
+/// epochs = 5
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The URL of the Aggregator.
+    pub aggregator: Option<String>,
+    /// The URL of the Publisher.
+    pub publisher: Option<String>,
+    /// The output format.
+    pub format: Option<OutputFormat>,
+    /// The number of epochs Blobs and Quilts are stored for when `--epochs` is not given.
+    pub epochs: Option<u64>,
+    /// The request timeout, in seconds.
+    pub timeout_secs: Option<u64>,
+    /// The path, relative to the Aggregator, of an endpoint serving the output of `walrus info --json`.
+    pub network_info_path: Option<String>,
+}
+
+impl Config {
+    /// Loads the configuration file.
+    ///
+    /// An explicitly given file must exist, whereas a missing file at the default location yields an empty
+    /// configuration.
+    ///
+    /// # Arguments
+    /// - `path`: The path given with `--config` or `WALRUS_RS_CONFIG`, if any.
+    ///
+    /// # Returns
+    /// - `Ok(Config)`: The loaded configuration.
+    /// - `Err(WalrusError)`: If the file could not be read or is not a valid configuration.
+    pub fn load(path: Option<&Path>) -> Result<Self, WalrusError> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => match default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if !required && e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Self::default());
+            }
+            Err(e) => {
+                return Err(WalrusError::InvalidParameter(format!(
+                    "Failed to read config file {}: {e}",
+                    path.display()
+                )));
+            }
+        };
+        toml::from_str(&contents).map_err(|e| {
+            WalrusError::InvalidParameter(format!("Invalid config file {}: {e}", path.display()))
+        })
+    }
+}
+
+/// Returns the default location of the configuration file, `walrus-rs/config.toml` in the user's configuration
+/// directory.
+fn default_path() -> Option<PathBuf> {
+    let env_path = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+    let config_dir = if cfg!(windows) {
+        env_path("APPDATA").map(PathBuf::from)
+    } else {
+        env_path("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env_path("HOME").map(|home| PathBuf::from(home).join(".config")))
+    };
+    config_dir.map(|dir| dir.join("walrus-rs").join("config.toml"))
+}
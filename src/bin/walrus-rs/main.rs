@@ -0,0 +1,587 @@
+//! `walrus-rs` is a command-line tool for storing and reading Blobs and Quilts with Walrus, built on the
+//! [`BlockingWalrusClient`].
+//!
+//! The Aggregator and Publisher are taken from the `--aggregator` and `--publisher` flags, the `AGGREGATOR` and
+//! `PUBLISHER` environment variables, or the configuration file (see [`config::Config`]), in that order.
+
+mod config;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+use walrus_rs::models::{BlobStoreResult, QuiltMetadata, QuiltStoreResponse};
+use walrus_rs::{
+    BlobId, BlockingWalrusClient, ObjectId, QuiltDirOptions, QuiltPatchId, StoreOptions,
+    SuiAddress, WalrusError,
+};
+
+use crate::config::{Config, DEFAULT_AGGREGATOR, DEFAULT_PUBLISHER};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "walrus-rs",
+    version,
+    about = "Store and read Blobs and Quilts with Walrus"
+)]
+struct Cli {
+    /// The URL of the Aggregator.
+    #[arg(long, env = "AGGREGATOR", global = true)]
+    aggregator: Option<String>,
+
+    /// The URL of the Publisher.
+    #[arg(long, env = "PUBLISHER", global = true)]
+    publisher: Option<String>,
+
+    /// The path, relative to the Aggregator, of an endpoint serving the output of `walrus info --json`.
+    /// Required by `info` and `estimate-cost`; no public deployment serves it, so this must be a service you run.
+    #[arg(long, env = "WALRUS_NETWORK_INFO_PATH", global = true)]
+    network_info_path: Option<String>,
+
+    /// The configuration file [default: walrus-rs/config.toml in the user's configuration directory].
+    #[arg(long, env = "WALRUS_RS_CONFIG", global = true)]
+    config: Option<PathBuf>,
+
+    /// The output format of results [default: human].
+    #[arg(long, value_enum, global = true)]
+    format: Option<OutputFormat>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// The format results are printed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Readable text.
+    Human,
+    /// The JSON responses of the Walrus API.
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Store a file, or standard input, as a Blob.
+    Store {
+        /// The file to store. Reads standard input if omitted or `-`.
+        file: Option<PathBuf>,
+
+        #[command(flatten)]
+        options: StoreArgs,
+    },
+
+    /// Read a Blob into a file, or standard output.
+    Read {
+        /// The ID of the Blob, or of its Blob object with `--by-object-id`.
+        id: String,
+
+        /// Treat the ID as the ID of a Blob object.
+        #[arg(long)]
+        by_object_id: bool,
+
+        /// The file to write the Blob to. Writes to standard output if omitted or `-`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Write the contents of one or more Blobs to standard output.
+    Cat {
+        /// The IDs of the Blobs.
+        #[arg(required = true)]
+        blob_ids: Vec<BlobId>,
+    },
+
+    /// Show the metadata of a Blob.
+    #[command(visible_alias = "metadata")]
+    Head {
+        /// The ID of the Blob.
+        blob_id: BlobId,
+    },
+
+    /// Store files as a Quilt, identified by their file names.
+    StoreQuilt {
+        /// The files to store.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Tag a file of the Quilt. Can be repeated.
+        #[arg(long = "tag", value_name = "IDENTIFIER:KEY=VALUE", value_parser = parse_tag)]
+        tags: Vec<(String, String, String)>,
+
+        #[command(flatten)]
+        options: StoreArgs,
+    },
+
+    /// Store the files of a directory as one or more Quilts, identified by their relative paths.
+    StoreDir {
+        /// The directory to store.
+        dir: PathBuf,
+
+        /// Only store files matching this glob. Can be repeated.
+        #[arg(long, value_name = "GLOB")]
+        include: Vec<String>,
+
+        /// Skip files and directories matching this glob. Can be repeated.
+        #[arg(long, value_name = "GLOB")]
+        exclude: Vec<String>,
+
+        /// The maximum total size of the files of one Quilt, in bytes, excluding the Quilt index and padding.
+        #[arg(long, default_value_t = QuiltDirOptions::DEFAULT_MAX_QUILT_INPUT_SIZE)]
+        max_quilt_input_size: u64,
+
+        /// Follow symbolic links instead of skipping them.
+        #[arg(long)]
+        follow_symlinks: bool,
+
+        #[command(flatten)]
+        options: StoreArgs,
+    },
+
+    /// Show the current epoch, the epoch duration and the limits of the network.
+    /// Needs a network info service you run yourself, see `--network-info-path`.
+    Info,
+
+    /// Estimate the cost of storing a Blob of the given size at the current prices.
+    /// Needs a network info service you run yourself, see `--network-info-path`.
+    EstimateCost {
+        /// The size of the Blob in bytes.
+        size: u64,
+
+        /// The number of epochs to store the Blob for [default: from the configuration file, else 1].
+        #[arg(long)]
+        epochs: Option<u64>,
+    },
+
+    /// List the files of a Quilt.
+    ListQuilt {
+        /// The ID of the Quilt.
+        quilt_id: BlobId,
+    },
+
+    /// Download every file of a Quilt into a directory.
+    DownloadQuilt {
+        /// The ID of the Quilt.
+        quilt_id: BlobId,
+
+        /// The directory to write the files to.
+        #[arg(default_value = ".")]
+        dir: PathBuf,
+    },
+
+    /// Read a file of a Quilt into a file, or standard output.
+    ReadQuilt {
+        /// The Quilt patch ID of the file, or the Quilt ID if an identifier is given.
+        id: String,
+
+        /// The identifier of the file in the Quilt.
+        identifier: Option<String>,
+
+        /// The file to write the contents to. Writes to standard output if omitted or `-`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// The flags controlling how Blobs and Quilts are stored.
+#[derive(Debug, Args)]
+struct StoreArgs {
+    /// The number of epochs to store the data for [default: from the configuration file, else the Publisher's].
+    #[arg(long)]
+    epochs: Option<u64>,
+
+    /// Make the Blob deletable.
+    #[arg(long, conflicts_with = "permanent")]
+    deletable: bool,
+
+    /// Make the Blob permanent.
+    #[arg(long)]
+    permanent: bool,
+
+    /// Send the created Blob object to this Sui address.
+    #[arg(long, value_name = "ADDRESS")]
+    send_object_to: Option<SuiAddress>,
+}
+
+impl StoreArgs {
+    fn options(&self, config: &Config) -> StoreOptions {
+        let mut options = StoreOptions::new();
+        if let Some(epochs) = self.epochs.or(config.epochs) {
+            options = options.epochs(epochs);
+        }
+        if self.deletable {
+            options = options.deletable(true);
+        }
+        if self.permanent {
+            options = options.permanent(true);
+        }
+        if let Some(address) = self.send_object_to {
+            options = options.send_object_to(address);
+        }
+        options
+    }
+}
+
+/// Parses a `--tag` value of the form `IDENTIFIER:KEY=VALUE`.
+fn parse_tag(tag: &str) -> Result<(String, String, String), String> {
+    let (identifier, key_value) = tag.split_once(':').ok_or("expected IDENTIFIER:KEY=VALUE")?;
+    let (key, value) = key_value
+        .split_once('=')
+        .ok_or("expected IDENTIFIER:KEY=VALUE")?;
+    Ok((identifier.to_string(), key.to_string(), value.to_string()))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        // The reader of the output, e.g. `head`, has exited, which is not an error of this tool.
+        Err(WalrusError::Io(e)) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), WalrusError> {
+    let config = Config::load(cli.config.as_deref())?;
+    let format = cli.format.or(config.format).unwrap_or(OutputFormat::Human);
+    let aggregator = cli.aggregator.as_deref().or(config.aggregator.as_deref());
+    let publisher = cli.publisher.as_deref().or(config.publisher.as_deref());
+    let mut builder = BlockingWalrusClient::builder(
+        aggregator.unwrap_or(DEFAULT_AGGREGATOR),
+        publisher.unwrap_or(DEFAULT_PUBLISHER),
+    );
+    if let Some(timeout) = config.timeout_secs {
+        builder = builder.timeout(Duration::from_secs(timeout));
+    }
+    let network_info_path = cli.network_info_path.as_deref();
+    if let Some(path) = network_info_path.or(config.network_info_path.as_deref()) {
+        builder = builder.network_info_path(path);
+    }
+    let client = builder.build()?;
+
+    match cli.command {
+        Command::Store { file, options } => {
+            let options = options.options(&config);
+            let result = match file.filter(|file| !is_stdio(file)) {
+                Some(file) => client.store_blob_from_path(file, &options)?,
+                None => {
+                    let mut data = Vec::new();
+                    io::stdin().lock().read_to_end(&mut data)?;
+                    client.store_blob(data, &options)?
+                }
+            };
+            match format {
+                OutputFormat::Json => print_json(&result),
+                OutputFormat::Human => {
+                    let mut out = io::stdout().lock();
+                    write_store_result(&mut out, "Blob ID", &result)?;
+                    Ok(())
+                }
+            }
+        }
+        Command::Read {
+            id,
+            by_object_id,
+            output,
+        } => {
+            let mut reader = if by_object_id {
+                client.read_blob_reader_by_object_id(&id.parse::<ObjectId>()?)?
+            } else {
+                client.read_blob_reader_by_id(&id.parse::<BlobId>()?)?
+            };
+            copy_to_output(&mut reader, output.as_deref())
+        }
+        Command::Cat { blob_ids } => {
+            let mut out = BufWriter::new(io::stdout().lock());
+            for blob_id in &blob_ids {
+                io::copy(&mut client.read_blob_reader_by_id(blob_id)?, &mut out)?;
+            }
+            out.flush()?;
+            Ok(())
+        }
+        Command::Head { blob_id } => {
+            let metadata = client.get_blob_metadata(&blob_id)?;
+            match format {
+                OutputFormat::Json => print_json(&metadata),
+                OutputFormat::Human => {
+                    let mut out = io::stdout().lock();
+                    writeln!(out, "Content-Length: {}", metadata.content_length)?;
+                    writeln!(out, "Content-Type: {}", metadata.content_type)?;
+                    writeln!(out, "ETag: {}", metadata.etag)?;
+                    Ok(())
+                }
+            }
+        }
+        Command::StoreQuilt {
+            files,
+            tags,
+            options,
+        } => {
+            let mut contents = Vec::with_capacity(files.len());
+            for file in &files {
+                let identifier = file
+                    .file_name()
+                    .ok_or_else(|| {
+                        WalrusError::InvalidParameter(format!("{} is not a file", file.display()))
+                    })?
+                    .to_string_lossy()
+                    .into_owned();
+                contents.push((identifier, std::fs::read(file)?));
+            }
+            let metadata = quilt_metadata(&contents, tags)?;
+            let files = contents
+                .iter()
+                .map(|(identifier, data)| (identifier.as_str(), data.clone()))
+                .collect();
+            let response = client.store_quilt(files, metadata, &options.options(&config))?;
+            match format {
+                OutputFormat::Json => print_json(&response),
+                OutputFormat::Human => Ok(write_quilt_store_response(
+                    &mut io::stdout().lock(),
+                    &response,
+                )?),
+            }
+        }
+        Command::StoreDir {
+            dir,
+            include,
+            exclude,
+            max_quilt_input_size,
+            follow_symlinks,
+            options,
+        } => {
+            let mut dir_options = QuiltDirOptions::new()
+                .max_quilt_input_size(max_quilt_input_size)
+                .follow_symlinks(follow_symlinks);
+            for glob in include {
+                dir_options = dir_options.include(glob);
+            }
+            for glob in exclude {
+                dir_options = dir_options.exclude(glob);
+            }
+            let responses =
+                client.store_quilt_from_dir(&dir, &dir_options, &options.options(&config))?;
+            match format {
+                OutputFormat::Json => print_json(&responses),
+                OutputFormat::Human => {
+                    let mut out = io::stdout().lock();
+                    for response in &responses {
+                        write_quilt_store_response(&mut out, response)?;
+                    }
+                    Ok(())
+                }
+            }
+        }
+        Command::Info => {
+            let info = client.network_info()?;
+            match format {
+                OutputFormat::Json => print_json(&info),
+                OutputFormat::Human => {
+                    let mut out = io::stdout().lock();
+                    writeln!(out, "Current epoch: {}", info.current_epoch)?;
+                    if let Some(start) = info.current_epoch_start {
+                        writeln!(
+                            out,
+                            "Epoch started: {}",
+                            humantime::format_rfc3339_seconds(start)
+                        )?;
+                    }
+                    writeln!(
+                        out,
+                        "Epoch duration: {}",
+                        humantime::format_duration(info.epoch_duration)
+                    )?;
+                    writeln!(out, "Max epochs ahead: {}", info.max_epochs_ahead)?;
+                    writeln!(out, "Max blob size: {} bytes", info.max_blob_size)?;
+                    writeln!(out, "Shards: {}", info.n_shards)?;
+                    Ok(())
+                }
+            }
+        }
+        Command::EstimateCost { size, epochs } => {
+            let estimate =
+                client.estimate_store_cost(size, epochs.or(config.epochs).unwrap_or(1))?;
+            match format {
+                OutputFormat::Json => print_json(&estimate),
+                OutputFormat::Human => {
+                    let mut out = io::stdout().lock();
+                    writeln!(out, "Encoded length: {} bytes", estimate.encoded_length)?;
+                    writeln!(out, "Storage units: {}", estimate.storage_units)?;
+                    writeln!(
+                        out,
+                        "Storage cost: {} FROST for {} epochs",
+                        estimate.storage_cost, estimate.epochs
+                    )?;
+                    writeln!(out, "Write cost: {} FROST", estimate.write_cost)?;
+                    writeln!(out, "Total cost: {} FROST", estimate.total_cost()?)?;
+                    Ok(())
+                }
+            }
+        }
+        Command::ListQuilt { quilt_id } => {
+            let patches = client.list_quilt_patches(&quilt_id)?;
+            match format {
+                OutputFormat::Json => print_json(&patches),
+                OutputFormat::Human => {
+                    let mut out = io::stdout().lock();
+                    for patch in &patches {
+                        writeln!(out, "{}", patch.identifier)?;
+                        writeln!(out, "  Patch ID: {}", patch.quilt_patch_id)?;
+                        if let Some(size) = patch.size {
+                            writeln!(out, "  Size: {size} bytes")?;
+                        }
+                        let mut tags: Vec<_> = patch.tags.iter().collect();
+                        tags.sort();
+                        for (key, value) in tags {
+                            writeln!(out, "  Tag: {key}={value}")?;
+                        }
+                    }
+                    Ok(())
+                }
+            }
+        }
+        Command::DownloadQuilt { quilt_id, dir } => {
+            let paths = client.download_quilt_to_dir(&quilt_id, &dir)?;
+            match format {
+                OutputFormat::Json => print_json(&paths),
+                OutputFormat::Human => {
+                    let mut out = io::stdout().lock();
+                    for path in &paths {
+                        writeln!(out, "{}", path.display())?;
+                    }
+                    Ok(())
+                }
+            }
+        }
+        Command::ReadQuilt {
+            id,
+            identifier,
+            output,
+        } => {
+            let mut reader = match identifier {
+                Some(identifier) => client.read_quilt_blob_reader_by_quilt_id_and_identifier(
+                    &id.parse::<BlobId>()?,
+                    &identifier,
+                )?,
+                None => client.read_quilt_blob_reader_by_patch_id(&id.parse::<QuiltPatchId>()?)?,
+            };
+            copy_to_output(&mut reader, output.as_deref())
+        }
+    }
+}
+
+/// Groups the `--tag` values of `store-quilt` into the metadata of the Quilt's files.
+fn quilt_metadata(
+    files: &[(String, Vec<u8>)],
+    tags: Vec<(String, String, String)>,
+) -> Result<Option<Vec<QuiltMetadata>>, WalrusError> {
+    if tags.is_empty() {
+        return Ok(None);
+    }
+    let mut grouped: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for (identifier, key, value) in tags {
+        if !files.iter().any(|(name, _)| *name == identifier) {
+            return Err(WalrusError::InvalidParameter(format!(
+                "Tag refers to unknown Quilt identifier {identifier:?}"
+            )));
+        }
+        grouped.entry(identifier).or_default().insert(key, value);
+    }
+    let metadata = grouped
+        .into_iter()
+        .map(|(identifier, tags)| QuiltMetadata { identifier, tags })
+        .collect();
+    Ok(Some(metadata))
+}
+
+/// Returns whether a path argument refers to standard input or output.
+fn is_stdio(path: &Path) -> bool {
+    path == Path::new("-")
+}
+
+/// Copies a Blob to the given file, or to standard output.
+fn copy_to_output(reader: &mut impl Read, output: Option<&Path>) -> Result<(), WalrusError> {
+    match output.filter(|output| !is_stdio(output)) {
+        Some(path) => {
+            let mut file = BufWriter::new(File::create(path)?);
+            io::copy(reader, &mut file)?;
+            file.flush()?;
+        }
+        None => {
+            let mut out = BufWriter::new(io::stdout().lock());
+            io::copy(reader, &mut out)?;
+            out.flush()?;
+        }
+    }
+    Ok(())
+}
+
+fn print_json(value: &impl Serialize) -> Result<(), WalrusError> {
+    let mut out = io::stdout().lock();
+    serde_json::to_writer_pretty(&mut out, value)
+        .map_err(|e| WalrusError::ParseError(format!("Failed to serialize output: {e}")))?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn write_store_result(
+    out: &mut impl Write,
+    id_label: &str,
+    result: &BlobStoreResult,
+) -> io::Result<()> {
+    match result {
+        BlobStoreResult::NewlyCreated(created) => {
+            let blob = &created.blob_object;
+            writeln!(out, "{id_label}: {}", blob.blob_id)?;
+            writeln!(out, "Status: newly created")?;
+            writeln!(out, "Object ID: {}", blob.id)?;
+            writeln!(out, "Size: {} bytes", blob.size)?;
+            writeln!(out, "End epoch: {}", blob.storage.end_epoch)?;
+            writeln!(out, "Deletable: {}", blob.deletable)?;
+            writeln!(out, "Cost: {}", created.cost)?;
+        }
+        BlobStoreResult::AlreadyCertified(certified) => {
+            writeln!(out, "{id_label}: {}", certified.blob_id)?;
+            writeln!(out, "Status: already certified")?;
+            if let Some(object_id) = &certified.object {
+                writeln!(out, "Object ID: {object_id}")?;
+            }
+            writeln!(out, "End epoch: {}", certified.end_epoch)?;
+        }
+        BlobStoreResult::MarkedInvalid(invalid) => {
+            writeln!(out, "{id_label}: {}", invalid.blob_id)?;
+            writeln!(out, "Status: marked invalid")?;
+        }
+        BlobStoreResult::Error(failure) => {
+            if let Some(blob_id) = &failure.blob_id {
+                writeln!(out, "{id_label}: {blob_id}")?;
+            }
+            writeln!(out, "Status: failed in phase {}", failure.failure_phase)?;
+            writeln!(out, "Error: {}", failure.error_msg)?;
+        }
+        BlobStoreResult::Unknown(value) => {
+            writeln!(out, "Status: unknown result {value}")?;
+        }
+    }
+    Ok(())
+}
+
+fn write_quilt_store_response(
+    out: &mut impl Write,
+    response: &QuiltStoreResponse,
+) -> io::Result<()> {
+    write_store_result(out, "Quilt ID", &response.blob_store_result)?;
+    writeln!(out, "Patches:")?;
+    for patch in &response.stored_quilt_blobs {
+        writeln!(out, "  {}: {}", patch.identifier, patch.quilt_patch_id)?;
+    }
+    Ok(())
+}
@@ -1,13 +1,119 @@
+use std::future::Future;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::Thread;
+use std::time::Duration;
+
+use bytes::{Buf, Bytes};
+use futures_util::StreamExt;
+
+use reqwest::{
+    Certificate, Client, Proxy,
+    header::{HeaderMap, HeaderName, HeaderValue},
+};
+
+use crate::builder::WalrusClientBuilder;
+use crate::chunked::{ChunkManifest, ChunkedStoreResult, ChunkedUploadOptions};
 use crate::client::WalrusClient;
+use crate::encoding::EncodingConfig;
+use crate::endpoint::SelectionStrategy;
 use crate::error::WalrusError;
-use crate::models::{BlobMetadata, BlobStoreResult, QuiltMetadata, QuiltStoreResponse};
+use crate::ids::{BlobId, ObjectId, QuiltPatchId};
+use crate::models::{
+    BlobMetadata, BlobStoreResult, QuiltMetadata, QuiltPatchItem, QuiltPatchResponse,
+    QuiltStoreResponse,
+};
+use crate::network::{NetworkInfo, StoreCostEstimate};
+use crate::options::StoreOptions;
+use crate::progress::ProgressObserver;
+use crate::quilt_dir::QuiltDirOptions;
+use crate::range::ByteRange;
+use crate::retry::RetryPolicy;
+use crate::stream::BlobStream;
 use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+
+/// The size of the chunks read from a `std::io::Read` when storing Blobs.
+const READER_CHUNK_SIZE: usize = 64 * 1024;
+/// The number of chunks buffered between the reading thread and the upload.
+const READER_CHANNEL_CAPACITY: usize = 4;
+
+/// Returns the runtime shared by all blocking clients, creating it on first use.
+///
+/// The runtime drives I/O and timers on a thread of its own, while the futures of requests are polled by the
+/// threads blocking on them (see [`block_on`]). If creating the runtime fails, the next call tries again.
+fn runtime() -> Result<&'static Runtime, WalrusError> {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    static INIT: Mutex<()> = Mutex::new(());
+
+    if let Some(runtime) = RUNTIME.get() {
+        return Ok(runtime);
+    }
+    // Only one runtime is built, as dropping a spare one would panic on threads of an async runtime.
+    let _init = INIT.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(runtime) = RUNTIME.get() {
+        return Ok(runtime);
+    }
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("walrus-blocking-client")
+        .enable_all()
+        .build()
+        .map_err(|e| WalrusError::Other(format!("Failed to create the Tokio runtime: {e}")))?;
+    Ok(RUNTIME.get_or_init(|| runtime))
+}
+
+/// Runs a request to completion, blocking the current thread.
+fn block_on<T>(future: impl Future<Output = Result<T, WalrusError>>) -> Result<T, WalrusError> {
+    poll_to_completion(future)?
+}
+
+/// Polls a future on the current thread until it completes, parking the thread while it is pending.
+///
+/// The future runs in the context of the shared runtime, which drives its I/O and timers and wakes the thread.
+/// Unlike `Runtime::block_on`, this does not enter the runtime, so it works on any thread, including threads of
+/// other Tokio runtimes.
+fn poll_to_completion<F: Future>(future: F) -> Result<F::Output, WalrusError> {
+    let _context = runtime()?.enter();
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return Ok(output),
+            // Spurious wake-ups merely poll the future once more.
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+/// Wakes a thread parked in [`poll_to_completion`].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
 
 /// `BlockingWalrusClient` is a blocking Walrus API client.
 /// It provides a synchronous interface by internally using an asynchronous `WalrusClient` and blocking the current thread.
+///
+/// All blocking clients share a single lazily created Tokio runtime, so creating clients is cheap. Like
+/// [`WalrusClient`], a client is `Send + Sync` and cheap to clone, so one client can be cloned into several threads.
+///
+/// The blocking client works on any thread. On a thread driving an async runtime, e.g. inside `#[tokio::main]` or
+/// an async task, it blocks that thread and thereby the tasks of the runtime, so use [`WalrusClient`] there, or move
+/// the blocking calls to `tokio::task::spawn_blocking`.
+#[derive(Clone)]
 pub struct BlockingWalrusClient {
     async_client: WalrusClient,
-    runtime: Runtime,
 }
 
 impl BlockingWalrusClient {
@@ -19,14 +125,18 @@ impl BlockingWalrusClient {
     ///
     /// # Returns
     /// - `Ok(BlockingWalrusClient)`: Successfully created a client instance.
-    /// - `Err(WalrusError)`: If the provided URL is invalid or the Tokio runtime creation fails.
+    /// - `Err(WalrusError)`: If the provided URL is invalid.
     pub fn new(aggregator_url: &str, publisher_url: &str) -> Result<Self, WalrusError> {
-        let async_client = WalrusClient::new(aggregator_url, publisher_url)?;
-        let runtime = Runtime::new().map_err(|e| WalrusError::Other(e.to_string()))?;
-        Ok(Self {
-            async_client,
-            runtime,
-        })
+        Self::builder(aggregator_url, publisher_url).build()
+    }
+
+    /// Creates a [`BlockingWalrusClientBuilder`] to configure a `BlockingWalrusClient`.
+    ///
+    /// # Arguments
+    /// - `aggregator_url`: The URL string for the Walrus Aggregator service.
+    /// - `publisher_url`: The URL string for the Walrus Publisher service.
+    pub fn builder(aggregator_url: &str, publisher_url: &str) -> BlockingWalrusClientBuilder {
+        BlockingWalrusClientBuilder::new(aggregator_url, publisher_url)
     }
 
     /// Stores a Blob to the Walrus Publisher service (blocking version).
@@ -35,10 +145,7 @@ impl BlockingWalrusClient {
     ///
     /// # Arguments
     /// - `data`: The Blob data to store.
-    /// - `epochs`: Optional, the number of epochs for the Blob's lifecycle.
-    /// - `deletable`: Optional, indicates if the Blob is deletable.
-    /// - `permanent`: Optional, indicates if the Blob is permanently stored.
-    /// - `send_object_to`: Optional, specifies where to send the object.
+    /// - `options`: The [`StoreOptions`] controlling how the Blob is stored.
     ///
     /// # Returns
     /// - `Ok(BlobStoreResult)`: Successfully stored the Blob and returned the result.
@@ -46,18 +153,77 @@ impl BlockingWalrusClient {
     pub fn store_blob(
         &self,
         data: impl Into<reqwest::Body> + Send,
-        epochs: Option<u64>,
-        deletable: Option<bool>,
-        permanent: Option<bool>,
-        send_object_to: Option<&str>,
+        options: &StoreOptions,
+    ) -> Result<BlobStoreResult, WalrusError> {
+        block_on(self.async_client.store_blob(data, options))
+    }
+
+    /// Stores the contents of a file as a Blob to the Walrus Publisher service (blocking version).
+    ///
+    /// The file is streamed to the Publisher without loading it into memory.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to store.
+    /// - `options`: The [`StoreOptions`] controlling how the Blob is stored.
+    ///
+    /// # Returns
+    /// - `Ok(BlobStoreResult)`: Successfully stored the Blob and returned the result.
+    /// - `Err(WalrusError)`: If storing failed.
+    pub fn store_blob_from_path(
+        &self,
+        path: impl AsRef<Path>,
+        options: &StoreOptions,
+    ) -> Result<BlobStoreResult, WalrusError> {
+        block_on(self.async_client.store_blob_from_path(path, options))
+    }
+
+    /// Stores data read from a reader as a Blob to the Walrus Publisher service (blocking version).
+    ///
+    /// Exactly `length` bytes are read on a background thread and streamed to the Publisher.
+    /// Since the reader cannot be rewound, the request is not retried.
+    ///
+    /// # Arguments
+    /// - `reader`: The reader providing the Blob data.
+    /// - `length`: The number of bytes to read and store.
+    /// - `options`: The [`StoreOptions`] controlling how the Blob is stored.
+    ///
+    /// # Returns
+    /// - `Ok(BlobStoreResult)`: Successfully stored the Blob and returned the result.
+    /// - `Err(WalrusError)`: If storing failed.
+    pub fn store_blob_from_reader(
+        &self,
+        reader: impl Read + Send + 'static,
+        length: u64,
+        options: &StoreOptions,
     ) -> Result<BlobStoreResult, WalrusError> {
-        self.runtime.block_on(self.async_client.store_blob(
-            data,
-            epochs,
-            deletable,
-            permanent,
-            send_object_to,
-        ))
+        let (sender, receiver) = mpsc::channel::<io::Result<Bytes>>(READER_CHANNEL_CAPACITY);
+        std::thread::spawn(move || {
+            let mut reader = reader.take(length);
+            loop {
+                let mut buf = vec![0u8; READER_CHUNK_SIZE];
+                let chunk = match reader.read(&mut buf) {
+                    Ok(0) => return,
+                    Ok(n) => {
+                        buf.truncate(n);
+                        Ok(Bytes::from(buf))
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(e) => Err(e),
+                };
+                let failed = chunk.is_err();
+                if sender.blocking_send(chunk).is_err() || failed {
+                    return;
+                }
+            }
+        });
+        let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|chunk| (chunk, receiver))
+        });
+
+        block_on(
+            self.async_client
+                .store_blob_from_stream(stream, length, options),
+        )
     }
 
     /// Reads Blob data by Blob ID from the Walrus Aggregator service (blocking version).
@@ -70,9 +236,43 @@ impl BlockingWalrusClient {
     /// # Returns
     /// - `Ok(Vec<u8>)`: Successfully read the Blob data.
     /// - `Err(WalrusError)`: If reading failed.
-    pub fn read_blob_by_id(&self, blob_id: &str) -> Result<Vec<u8>, WalrusError> {
-        self.runtime
-            .block_on(self.async_client.read_blob_by_id(blob_id))
+    pub fn read_blob_by_id(&self, blob_id: &BlobId) -> Result<Vec<u8>, WalrusError> {
+        block_on(self.async_client.read_blob_by_id(blob_id))
+    }
+
+    /// Opens a reader for Blob data by Blob ID from the Walrus Aggregator service (blocking version).
+    ///
+    /// This method blocks until the response has been received; the data is then read incrementally
+    /// through the returned [`BlobReader`] without buffering the whole Blob in memory.
+    ///
+    /// # Arguments
+    /// - `blob_id`: The unique identifier of the Blob.
+    ///
+    /// # Returns
+    /// - `Ok(BlobReader)`: A reader over the Blob data.
+    /// - `Err(WalrusError)`: If the request failed.
+    pub fn read_blob_reader_by_id(&self, blob_id: &BlobId) -> Result<BlobReader, WalrusError> {
+        let stream = block_on(self.async_client.read_blob_stream_by_id(blob_id))?;
+        Ok(BlobReader::new(stream))
+    }
+
+    /// Reads a byte range of a Blob by Blob ID from the Walrus Aggregator service (blocking version).
+    ///
+    /// See [`WalrusClient::read_blob_range`] for how the range is read.
+    ///
+    /// # Arguments
+    /// - `blob_id`: The unique identifier of the Blob.
+    /// - `range`: The bytes to read, e.g. `0..1024` or [`ByteRange::suffix`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes.
+    /// - `Err(WalrusError)`: If the range is empty or reading failed.
+    pub fn read_blob_range(
+        &self,
+        blob_id: &BlobId,
+        range: impl Into<ByteRange>,
+    ) -> Result<Vec<u8>, WalrusError> {
+        block_on(self.async_client.read_blob_range(blob_id, range))
     }
 
     /// Reads Blob data by object ID from the Walrus Aggregator service (blocking version).
@@ -85,9 +285,129 @@ impl BlockingWalrusClient {
     /// # Returns
     /// - `Ok(Vec<u8>)`: Successfully read the Blob data.
     /// - `Err(WalrusError)`: If reading failed.
-    pub fn read_blob_by_object_id(&self, object_id: &str) -> Result<Vec<u8>, WalrusError> {
-        self.runtime
-            .block_on(self.async_client.read_blob_by_object_id(object_id))
+    pub fn read_blob_by_object_id(&self, object_id: &ObjectId) -> Result<Vec<u8>, WalrusError> {
+        block_on(self.async_client.read_blob_by_object_id(object_id))
+    }
+
+    /// Opens a reader for Blob data by object ID from the Walrus Aggregator service (blocking version).
+    ///
+    /// # Arguments
+    /// - `object_id`: The unique identifier of the object.
+    ///
+    /// # Returns
+    /// - `Ok(BlobReader)`: A reader over the Blob data.
+    /// - `Err(WalrusError)`: If the request failed.
+    pub fn read_blob_reader_by_object_id(
+        &self,
+        object_id: &ObjectId,
+    ) -> Result<BlobReader, WalrusError> {
+        let stream = block_on(self.async_client.read_blob_stream_by_object_id(object_id))?;
+        Ok(BlobReader::new(stream))
+    }
+
+    /// Reads a byte range of a Blob by object ID from the Walrus Aggregator service (blocking version).
+    ///
+    /// # Arguments
+    /// - `object_id`: The unique identifier of the object.
+    /// - `range`: The bytes to read, e.g. `0..1024` or [`ByteRange::suffix`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes.
+    /// - `Err(WalrusError)`: If the range is empty or reading failed.
+    pub fn read_blob_range_by_object_id(
+        &self,
+        object_id: &ObjectId,
+        range: impl Into<ByteRange>,
+    ) -> Result<Vec<u8>, WalrusError> {
+        block_on(
+            self.async_client
+                .read_blob_range_by_object_id(object_id, range),
+        )
+    }
+
+    /// Stores a large file as chunks, plus a manifest Blob listing them (blocking version).
+    ///
+    /// See [`WalrusClient::store_file_chunked`] for details.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to store.
+    /// - `chunked_options`: The [`ChunkedUploadOptions`] controlling how the file is split.
+    /// - `options`: The [`StoreOptions`] used for the chunks and the manifest.
+    ///
+    /// # Returns
+    /// - `Ok(ChunkedStoreResult)`: The Blob ID of the manifest, together with the manifest.
+    /// - `Err(WalrusError)`: If the options are invalid, or reading the file or storing failed.
+    pub fn store_file_chunked(
+        &self,
+        path: impl AsRef<Path>,
+        chunked_options: &ChunkedUploadOptions,
+        options: &StoreOptions,
+    ) -> Result<ChunkedStoreResult, WalrusError> {
+        block_on(
+            self.async_client
+                .store_file_chunked(path, chunked_options, options),
+        )
+    }
+
+    /// Reads the [`ChunkManifest`] of a file stored with [`BlockingWalrusClient::store_file_chunked`]
+    /// (blocking version).
+    ///
+    /// # Arguments
+    /// - `manifest_blob_id`: The Blob ID of the manifest.
+    ///
+    /// # Returns
+    /// - `Ok(ChunkManifest)`: The manifest.
+    /// - `Err(WalrusError)`: If reading failed or the Blob is not a chunk manifest.
+    pub fn read_chunk_manifest(
+        &self,
+        manifest_blob_id: &BlobId,
+    ) -> Result<ChunkManifest, WalrusError> {
+        block_on(self.async_client.read_chunk_manifest(manifest_blob_id))
+    }
+
+    /// Reads a file stored with [`BlockingWalrusClient::store_file_chunked`] and reassembles it in memory
+    /// (blocking version).
+    ///
+    /// # Arguments
+    /// - `manifest_blob_id`: The Blob ID of the manifest.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The file data.
+    /// - `Err(WalrusError)`: If reading failed or a chunk does not match the manifest.
+    pub fn read_chunked_blob(&self, manifest_blob_id: &BlobId) -> Result<Vec<u8>, WalrusError> {
+        block_on(self.async_client.read_chunked_blob(manifest_blob_id))
+    }
+
+    /// Reads a file stored with [`BlockingWalrusClient::store_file_chunked`] and writes it to `writer` chunk by
+    /// chunk (blocking version).
+    ///
+    /// # Arguments
+    /// - `manifest_blob_id`: The Blob ID of the manifest.
+    /// - `writer`: The destination of the file data.
+    ///
+    /// # Returns
+    /// - `Ok(u64)`: The number of bytes written.
+    /// - `Err(WalrusError)`: If reading or writing failed, or a chunk does not match the manifest.
+    pub fn read_chunked_blob_to_writer(
+        &self,
+        manifest_blob_id: &BlobId,
+        mut writer: impl Write,
+    ) -> Result<u64, WalrusError> {
+        block_on(async {
+            let manifest = self
+                .async_client
+                .read_chunk_manifest(manifest_blob_id)
+                .await?;
+            let mut chunks = std::pin::pin!(self.async_client.read_chunks(&manifest));
+            let mut written = 0;
+            while let Some(chunk) = chunks.next().await {
+                let chunk = chunk?;
+                writer.write_all(&chunk)?;
+                written += chunk.len() as u64;
+            }
+            writer.flush()?;
+            Ok(written)
+        })
     }
 
     /// Stores a Quilt (multiple files) to the Walrus Publisher service (blocking version).
@@ -97,10 +417,7 @@ impl BlockingWalrusClient {
     /// # Arguments
     /// - `files`: A vector of tuples containing the filename and file content.
     /// - `metadata`: Optional, metadata for the Quilt.
-    /// - `epochs`: Optional, the number of epochs for the Quilt's lifecycle.
-    /// - `deletable`: Optional, indicates if the Quilt is deletable.
-    /// - `permanent`: Optional, indicates if the Quilt is permanently stored.
-    /// - `send_object_to`: Optional, specifies where to send the object.
+    /// - `options`: The [`StoreOptions`] controlling how the Quilt is stored.
     ///
     /// # Returns
     /// - `Ok(QuiltStoreResponse)`: Successfully stored the Quilt and returned the result.
@@ -109,19 +426,53 @@ impl BlockingWalrusClient {
         &self,
         files: Vec<(&str, Vec<u8>)>,
         metadata: Option<Vec<QuiltMetadata>>,
-        epochs: Option<u64>,
-        deletable: Option<bool>,
-        permanent: Option<bool>,
-        send_object_to: Option<&str>,
+        options: &StoreOptions,
     ) -> Result<QuiltStoreResponse, WalrusError> {
-        self.runtime.block_on(self.async_client.store_quilt(
-            files,
-            metadata,
-            epochs,
-            deletable,
-            permanent,
-            send_object_to,
-        ))
+        block_on(self.async_client.store_quilt(files, metadata, options))
+    }
+
+    /// Stores the files of a directory as one or more Quilts (blocking version).
+    ///
+    /// See [`WalrusClient::store_quilt_from_dir`] for details.
+    ///
+    /// # Arguments
+    /// - `path`: The directory to store.
+    /// - `dir_options`: The [`QuiltDirOptions`] selecting the files and limiting the Quilts.
+    /// - `options`: The [`StoreOptions`] used for every Quilt.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<QuiltStoreResponse>)`: The results of storing the Quilts, in identifier order.
+    /// - `Err(WalrusError)`: If the options are invalid, or reading the directory or storing failed.
+    pub fn store_quilt_from_dir(
+        &self,
+        path: impl AsRef<Path>,
+        dir_options: &QuiltDirOptions,
+        options: &StoreOptions,
+    ) -> Result<Vec<QuiltStoreResponse>, WalrusError> {
+        block_on(
+            self.async_client
+                .store_quilt_from_dir(path, dir_options, options),
+        )
+    }
+
+    /// Downloads every file of a Quilt into a directory (blocking version).
+    ///
+    /// See [`WalrusClient::download_quilt_to_dir`] for details.
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The ID of the Quilt.
+    /// - `dir`: The directory to write the files to.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PathBuf>)`: The paths of the written files, in the order listed by the Aggregator.
+    /// - `Err(WalrusError)`: If an identifier is unsafe, a file does not match its tags, or reading or writing
+    ///   failed.
+    pub fn download_quilt_to_dir(
+        &self,
+        quilt_id: &BlobId,
+        dir: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>, WalrusError> {
+        block_on(self.async_client.download_quilt_to_dir(quilt_id, dir))
     }
 
     /// Reads Quilt Blob data by Quilt Patch ID from the Walrus Aggregator service (blocking version).
@@ -136,14 +487,53 @@ impl BlockingWalrusClient {
     /// - `Err(WalrusError)`: If reading failed.
     pub fn read_quilt_blob_by_patch_id(
         &self,
-        quilt_patch_id: &str,
+        quilt_patch_id: &QuiltPatchId,
     ) -> Result<Vec<u8>, WalrusError> {
-        self.runtime.block_on(
+        block_on(
             self.async_client
                 .read_quilt_blob_by_patch_id(quilt_patch_id),
         )
     }
 
+    /// Opens a reader for Quilt Blob data by Quilt Patch ID from the Walrus Aggregator service (blocking version).
+    ///
+    /// # Arguments
+    /// - `quilt_patch_id`: The unique identifier of the Quilt Patch.
+    ///
+    /// # Returns
+    /// - `Ok(BlobReader)`: A reader over the Quilt Blob data.
+    /// - `Err(WalrusError)`: If the request failed.
+    pub fn read_quilt_blob_reader_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+    ) -> Result<BlobReader, WalrusError> {
+        let stream = block_on(
+            self.async_client
+                .read_quilt_blob_stream_by_patch_id(quilt_patch_id),
+        )?;
+        Ok(BlobReader::new(stream))
+    }
+
+    /// Reads a byte range of a Quilt Blob by Quilt patch ID from the Walrus Aggregator service (blocking version).
+    ///
+    /// # Arguments
+    /// - `quilt_patch_id`: The unique identifier of the Quilt patch.
+    /// - `range`: The bytes to read, e.g. `0..1024` or [`ByteRange::suffix`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes.
+    /// - `Err(WalrusError)`: If the range is empty or reading failed.
+    pub fn read_quilt_blob_range_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+        range: impl Into<ByteRange>,
+    ) -> Result<Vec<u8>, WalrusError> {
+        block_on(
+            self.async_client
+                .read_quilt_blob_range_by_patch_id(quilt_patch_id, range),
+        )
+    }
+
     /// Reads Quilt Blob data by Quilt ID and identifier from the Walrus Aggregator service (blocking version).
     ///
     /// This method blocks the current thread until the Quilt Blob read operation is complete.
@@ -157,15 +547,164 @@ impl BlockingWalrusClient {
     /// - `Err(WalrusError)`: If reading failed.
     pub fn read_quilt_blob_by_quilt_id_and_identifier(
         &self,
-        quilt_id: &str,
+        quilt_id: &BlobId,
         identifier: &str,
     ) -> Result<Vec<u8>, WalrusError> {
-        self.runtime.block_on(
+        block_on(
             self.async_client
                 .read_quilt_blob_by_quilt_id_and_identifier(quilt_id, identifier),
         )
     }
 
+    /// Opens a reader for Quilt Blob data by Quilt ID and identifier from the Walrus Aggregator service (blocking version).
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The unique identifier of the Quilt.
+    /// - `identifier`: The identifier of the Blob within the Quilt.
+    ///
+    /// # Returns
+    /// - `Ok(BlobReader)`: A reader over the Quilt Blob data.
+    /// - `Err(WalrusError)`: If the request failed.
+    pub fn read_quilt_blob_reader_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<BlobReader, WalrusError> {
+        let stream = block_on(
+            self.async_client
+                .read_quilt_blob_stream_by_quilt_id_and_identifier(quilt_id, identifier),
+        )?;
+        Ok(BlobReader::new(stream))
+    }
+
+    /// Reads a byte range of a Quilt Blob by Quilt ID and identifier from the Walrus Aggregator service
+    /// (blocking version).
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The unique identifier of the Quilt.
+    /// - `identifier`: The identifier of the Blob within the Quilt.
+    /// - `range`: The bytes to read, e.g. `0..1024` or [`ByteRange::suffix`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes.
+    /// - `Err(WalrusError)`: If the range is empty or reading failed.
+    pub fn read_quilt_blob_range_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+        range: impl Into<ByteRange>,
+    ) -> Result<Vec<u8>, WalrusError> {
+        block_on(
+            self.async_client
+                .read_quilt_blob_range_by_quilt_id_and_identifier(quilt_id, identifier, range),
+        )
+    }
+
+    /// Reads a file of a Quilt by Quilt Patch ID from the Walrus Aggregator service, together with its identifier,
+    /// tags and the other response headers (blocking version).
+    ///
+    /// # Arguments
+    /// - `quilt_patch_id`: The unique identifier of the Quilt Patch.
+    ///
+    /// # Returns
+    /// - `Ok(QuiltPatchResponse)`: The data and attributes of the file.
+    /// - `Err(WalrusError)`: If reading failed.
+    pub fn read_quilt_patch_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+    ) -> Result<QuiltPatchResponse, WalrusError> {
+        block_on(
+            self.async_client
+                .read_quilt_patch_by_patch_id(quilt_patch_id),
+        )
+    }
+
+    /// Reads a file of a Quilt by Quilt ID and identifier from the Walrus Aggregator service, together with its
+    /// tags and the other response headers (blocking version).
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The unique identifier of the Quilt.
+    /// - `identifier`: The identifier of the Blob within the Quilt.
+    ///
+    /// # Returns
+    /// - `Ok(QuiltPatchResponse)`: The data and attributes of the file.
+    /// - `Err(WalrusError)`: If reading failed.
+    pub fn read_quilt_patch_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<QuiltPatchResponse, WalrusError> {
+        block_on(
+            self.async_client
+                .read_quilt_patch_by_quilt_id_and_identifier(quilt_id, identifier),
+        )
+    }
+
+    /// Lists the patches of a Quilt by Quilt ID from the Walrus Aggregator service (blocking version).
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The unique identifier of the Quilt.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<QuiltPatchItem>)`: The identifiers, Quilt Patch IDs and tags of the patches.
+    /// - `Err(WalrusError)`: If listing failed.
+    pub fn list_quilt_patches(
+        &self,
+        quilt_id: &BlobId,
+    ) -> Result<Vec<QuiltPatchItem>, WalrusError> {
+        block_on(self.async_client.list_quilt_patches(quilt_id))
+    }
+
+    /// Queries the status of the network from the endpoint set with
+    /// [`BlockingWalrusClientBuilder::network_info_path`] (blocking version).
+    ///
+    /// No public Walrus deployment serves this information, so this needs a service you run yourself; see
+    /// [`WalrusClient::network_info`] for details.
+    ///
+    /// # Returns
+    /// - `Ok(NetworkInfo)`: The epochs and limits of the network.
+    /// - `Err(WalrusError)`: If the request failed.
+    pub fn network_info(&self) -> Result<NetworkInfo, WalrusError> {
+        block_on(self.async_client.network_info())
+    }
+
+    /// Estimates the cost of storing a Blob at the current prices of the network (blocking version).
+    ///
+    /// See [`WalrusClient::estimate_store_cost`] for details.
+    ///
+    /// # Arguments
+    /// - `size`: The unencoded size of the Blob in bytes.
+    /// - `epochs`: The number of epochs to store the Blob for.
+    ///
+    /// # Returns
+    /// - `Ok(StoreCostEstimate)`: The encoded length of the Blob and the cost of storing it.
+    /// - `Err(WalrusError)`: If the parameters exceed the limits of the network, or querying it failed.
+    pub fn estimate_store_cost(
+        &self,
+        size: u64,
+        epochs: u64,
+    ) -> Result<StoreCostEstimate, WalrusError> {
+        block_on(self.async_client.estimate_store_cost(size, epochs))
+    }
+
+    /// Retrieves the OpenAPI specification of the Walrus Aggregator service (blocking version).
+    ///
+    /// # Returns
+    /// - `Ok(serde_json::Value)`: The OpenAPI document.
+    /// - `Err(WalrusError)`: If the request failed.
+    pub fn api_spec(&self) -> Result<serde_json::Value, WalrusError> {
+        block_on(self.async_client.api_spec())
+    }
+
+    /// Retrieves the OpenAPI specification of the Walrus Publisher service (blocking version).
+    ///
+    /// # Returns
+    /// - `Ok(serde_json::Value)`: The OpenAPI document.
+    /// - `Err(WalrusError)`: If the request failed.
+    pub fn publisher_api_spec(&self) -> Result<serde_json::Value, WalrusError> {
+        block_on(self.async_client.publisher_api_spec())
+    }
+
     /// Retrieves metadata for a Blob by its Blob ID from the Walrus Aggregator service (blocking version).
     ///
     /// This method blocks the current thread until the Blob metadata retrieval operation is complete.
@@ -176,8 +715,238 @@ impl BlockingWalrusClient {
     /// # Returns
     /// - `Ok(BlobMetadata)`: Successfully retrieved the Blob metadata.
     /// - `Err(WalrusError)`: If retrieval failed.
-    pub fn get_blob_metadata(&self, blob_id: &str) -> Result<BlobMetadata, WalrusError> {
-        self.runtime
-            .block_on(self.async_client.get_blob_metadata(blob_id))
+    pub fn get_blob_metadata(&self, blob_id: &BlobId) -> Result<BlobMetadata, WalrusError> {
+        block_on(self.async_client.get_blob_metadata(blob_id))
+    }
+}
+
+/// `BlobReader` reads Blob data from a [`BlobStream`] through the blocking `std::io::Read` interface.
+///
+/// Each call to `read` blocks the current thread until the next chunk has been received.
+/// Errors of the stream are returned as `std::io::Error` wrapping the [`WalrusError`].
+#[derive(Debug)]
+pub struct BlobReader {
+    stream: BlobStream,
+    chunk: Bytes,
+}
+
+impl BlobReader {
+    fn new(stream: BlobStream) -> Self {
+        Self {
+            stream,
+            chunk: Bytes::new(),
+        }
+    }
+
+    /// Returns the total length of the data, if announced by the server.
+    pub fn content_length(&self) -> Option<u64> {
+        self.stream.content_length()
+    }
+}
+
+impl Read for BlobReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.chunk.is_empty() {
+            match poll_to_completion(self.stream.next()).map_err(io::Error::other)? {
+                Some(chunk) => self.chunk = chunk.map_err(io::Error::other)?,
+                None => return Ok(0),
+            }
+        }
+        let len = buf.len().min(self.chunk.len());
+        buf[..len].copy_from_slice(&self.chunk[..len]);
+        self.chunk.advance(len);
+        Ok(len)
+    }
+}
+
+/// `BlockingWalrusClientBuilder` configures and creates a [`BlockingWalrusClient`].
+///
+/// It mirrors [`WalrusClientBuilder`] and supports the same HTTP transport options.
+#[derive(Debug)]
+pub struct BlockingWalrusClientBuilder {
+    inner: WalrusClientBuilder,
+}
+
+impl BlockingWalrusClientBuilder {
+    /// Creates a new `BlockingWalrusClientBuilder` for the given Aggregator and Publisher URLs.
+    pub fn new(aggregator_url: &str, publisher_url: &str) -> Self {
+        Self {
+            inner: WalrusClientBuilder::new(aggregator_url, publisher_url),
+        }
+    }
+
+    /// Adds a further Aggregator endpoint that requests fail over to.
+    pub fn add_aggregator_url(mut self, aggregator_url: &str) -> Self {
+        self.inner = self.inner.add_aggregator_url(aggregator_url);
+        self
+    }
+
+    /// Adds a further Publisher endpoint that requests fail over to.
+    pub fn add_publisher_url(mut self, publisher_url: &str) -> Self {
+        self.inner = self.inner.add_publisher_url(publisher_url);
+        self
+    }
+
+    /// Sets the strategy used to choose among the endpoints. Defaults to [`SelectionStrategy::RoundRobin`].
+    pub fn selection_strategy(mut self, strategy: SelectionStrategy) -> Self {
+        self.inner = self.inner.selection_strategy(strategy);
+        self
+    }
+
+    /// Sets the number of consecutive transient failures after which an endpoint is marked unhealthy.
+    pub fn failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.inner = self.inner.failure_threshold(failure_threshold);
+        self
+    }
+
+    /// Sets the duration an unhealthy endpoint is skipped before it is tried again.
+    pub fn unhealthy_cooldown(mut self, cooldown: Duration) -> Self {
+        self.inner = self.inner.unhealthy_cooldown(cooldown);
+        self
+    }
+
+    /// Uses an existing `reqwest::Client` instead of building a new one.
+    ///
+    /// When set, all other HTTP transport options of this builder are ignored.
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.inner = self.inner.http_client(http_client);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.connect_timeout(timeout);
+        self
+    }
+
+    /// Sets the total timeout of a request, from connecting until the response body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.timeout(timeout);
+        self
+    }
+
+    /// Sets the timeout for each read operation on a connection.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.read_timeout(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.inner = self.inner.user_agent(user_agent);
+        self
+    }
+
+    /// Adds a header sent with every request.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.inner = self.inner.default_header(name, value);
+        self
+    }
+
+    /// Adds a set of headers sent with every request.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.inner = self.inner.default_headers(headers);
+        self
+    }
+
+    /// Adds a proxy used for matching requests.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.inner = self.inner.proxy(proxy);
+        self
+    }
+
+    /// Disables all proxies, including the ones picked up from the environment.
+    pub fn no_proxy(mut self) -> Self {
+        self.inner = self.inner.no_proxy();
+        self
+    }
+
+    /// Adds a custom root certificate trusted when verifying server certificates.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.inner = self.inner.add_root_certificate(certificate);
+        self
+    }
+
+    /// Controls whether the built-in system root certificates are trusted.
+    pub fn tls_built_in_root_certs(mut self, enabled: bool) -> Self {
+        self.inner = self.inner.tls_built_in_root_certs(enabled);
+        self
+    }
+
+    /// Disables certificate validation.
+    ///
+    /// # Warning
+    /// This makes the connection vulnerable to man-in-the-middle attacks and should only be used for testing.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.inner = self.inner.danger_accept_invalid_certs(accept);
+        self
+    }
+
+    /// Only uses HTTP/2, without negotiating the protocol version first.
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.inner = self.inner.http2_prior_knowledge();
+        self
+    }
+
+    /// Only uses HTTP/1.
+    pub fn http1_only(mut self) -> Self {
+        self.inner = self.inner.http1_only();
+        self
+    }
+
+    /// Sets the policy used to retry failed requests. Defaults to [`RetryPolicy::default`].
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.inner = self.inner.retry_policy(retry_policy);
+        self
+    }
+
+    /// Sets the encoding configuration of the network, which determines the Blob ID of stored data.
+    /// Defaults to [`EncodingConfig::default`], matching Walrus Mainnet and Testnet.
+    pub fn encoding_config(mut self, encoding_config: EncodingConfig) -> Self {
+        self.inner = self.inner.encoding_config(encoding_config);
+        self
+    }
+
+    /// Enables verified reads: Blobs read by Blob ID are checked against their Blob ID before they are returned.
+    ///
+    /// See [`WalrusClientBuilder::verify_reads`] for details.
+    pub fn verify_reads(mut self, verify_reads: bool) -> Self {
+        self.inner = self.inner.verify_reads(verify_reads);
+        self
+    }
+
+    /// Sets an observer notified of the progress of uploads and downloads.
+    ///
+    /// See [`WalrusClientBuilder::progress_observer`] for details.
+    pub fn progress_observer(mut self, observer: impl ProgressObserver + 'static) -> Self {
+        self.inner = self.inner.progress_observer(observer);
+        self
+    }
+
+    /// Sets the path, relative to the Aggregator URLs, of an endpoint serving the [`NetworkInfo`] of the network.
+    ///
+    /// See [`WalrusClientBuilder::network_info_path`] for details.
+    pub fn network_info_path(mut self, path: impl Into<String>) -> Self {
+        self.inner = self.inner.network_info_path(path);
+        self
+    }
+
+    /// Sets the response headers reported as tags of Quilt patch reads.
+    ///
+    /// See [`WalrusClientBuilder::quilt_tag_headers`] for details.
+    pub fn quilt_tag_headers(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner = self.inner.quilt_tag_headers(names);
+        self
+    }
+
+    /// Builds the `BlockingWalrusClient`.
+    ///
+    /// # Returns
+    /// - `Ok(BlockingWalrusClient)`: Successfully created a client instance.
+    /// - `Err(WalrusError)`: If the provided URL is invalid or the HTTP client could not be built.
+    pub fn build(self) -> Result<BlockingWalrusClient, WalrusError> {
+        Ok(BlockingWalrusClient {
+            async_client: self.inner.build()?,
+        })
     }
 }
@@ -0,0 +1,307 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{
+    Certificate, Client, ClientBuilder, Proxy, Url,
+    header::{HeaderMap, HeaderName, HeaderValue},
+};
+
+use crate::client::WalrusClient;
+use crate::encoding::EncodingConfig;
+use crate::endpoint::{EndpointPool, SelectionStrategy};
+use crate::error::WalrusError;
+use crate::models::QuiltPatchResponse;
+use crate::progress::ProgressObserver;
+use crate::retry::RetryPolicy;
+
+/// `WalrusClientBuilder` configures and creates a [`WalrusClient`].
+///
+/// It carries the service URLs together with the HTTP transport options (timeouts, default headers,
+/// user agent, proxies, TLS and HTTP version preferences) used to build the underlying `reqwest::Client`.
+/// Alternatively, an existing `reqwest::Client` can be injected with [`WalrusClientBuilder::http_client`].
+///
+/// Additional Aggregator and Publisher endpoints can be added for failover; see [`EndpointPool`] for how
+/// endpoints are selected and how their health is tracked.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use walrus_rs::WalrusClient;
+///
+/// let client = WalrusClient::builder(
+///     "https://aggregator.testnet.walrus.atalma.io",
+///     "https://publisher.walrus-01.tududes.com",
+/// )
+/// .connect_timeout(Duration::from_secs(5))
+/// .timeout(Duration::from_secs(60))
+/// .user_agent("my-service/1.0")
+/// .build()?;
+/// # Ok::<(), walrus_rs::WalrusError>(())
+/// ```
+#[derive(Debug)]
+pub struct WalrusClientBuilder {
+    aggregator_urls: Vec<String>,
+    publisher_urls: Vec<String>,
+    selection_strategy: SelectionStrategy,
+    failure_threshold: u32,
+    unhealthy_cooldown: Duration,
+    http_client: Option<Client>,
+    client_builder: ClientBuilder,
+    default_headers: HeaderMap,
+    retry_policy: RetryPolicy,
+    encoding_config: EncodingConfig,
+    verify_reads: bool,
+    progress_observer: Option<Arc<dyn ProgressObserver>>,
+    network_info_path: Option<String>,
+    quilt_tag_headers: Vec<String>,
+}
+
+impl WalrusClientBuilder {
+    /// Creates a new `WalrusClientBuilder` for the given Aggregator and Publisher URLs.
+    ///
+    /// The URLs are validated when [`WalrusClientBuilder::build`] is called.
+    pub fn new(aggregator_url: &str, publisher_url: &str) -> Self {
+        Self {
+            aggregator_urls: vec![aggregator_url.to_owned()],
+            publisher_urls: vec![publisher_url.to_owned()],
+            selection_strategy: SelectionStrategy::default(),
+            failure_threshold: EndpointPool::DEFAULT_FAILURE_THRESHOLD,
+            unhealthy_cooldown: EndpointPool::DEFAULT_COOLDOWN,
+            http_client: None,
+            client_builder: Client::builder(),
+            default_headers: HeaderMap::new(),
+            retry_policy: RetryPolicy::default(),
+            encoding_config: EncodingConfig::default(),
+            verify_reads: false,
+            progress_observer: None,
+            network_info_path: None,
+            quilt_tag_headers: QuiltPatchResponse::DEFAULT_TAG_HEADERS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+
+    /// Adds a further Aggregator endpoint that requests fail over to.
+    pub fn add_aggregator_url(mut self, aggregator_url: &str) -> Self {
+        self.aggregator_urls.push(aggregator_url.to_owned());
+        self
+    }
+
+    /// Adds a further Publisher endpoint that requests fail over to.
+    pub fn add_publisher_url(mut self, publisher_url: &str) -> Self {
+        self.publisher_urls.push(publisher_url.to_owned());
+        self
+    }
+
+    /// Sets the strategy used to choose among the endpoints. Defaults to [`SelectionStrategy::RoundRobin`].
+    pub fn selection_strategy(mut self, strategy: SelectionStrategy) -> Self {
+        self.selection_strategy = strategy;
+        self
+    }
+
+    /// Sets the number of consecutive transient failures after which an endpoint is marked unhealthy.
+    pub fn failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold;
+        self
+    }
+
+    /// Sets the duration an unhealthy endpoint is skipped before it is tried again.
+    pub fn unhealthy_cooldown(mut self, cooldown: Duration) -> Self {
+        self.unhealthy_cooldown = cooldown;
+        self
+    }
+
+    /// Uses an existing `reqwest::Client` instead of building a new one.
+    ///
+    /// When set, all other HTTP transport options of this builder are ignored.
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.client_builder = self.client_builder.connect_timeout(timeout);
+        self
+    }
+
+    /// Sets the total timeout of a request, from connecting until the response body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.client_builder = self.client_builder.timeout(timeout);
+        self
+    }
+
+    /// Sets the timeout for each read operation on a connection.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.client_builder = self.client_builder.read_timeout(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.client_builder = self.client_builder.user_agent(user_agent.to_owned());
+        self
+    }
+
+    /// Adds a header sent with every request.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Adds a set of headers sent with every request.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Adds a proxy used for matching requests.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.client_builder = self.client_builder.proxy(proxy);
+        self
+    }
+
+    /// Disables all proxies, including the ones picked up from the environment.
+    pub fn no_proxy(mut self) -> Self {
+        self.client_builder = self.client_builder.no_proxy();
+        self
+    }
+
+    /// Adds a custom root certificate trusted when verifying server certificates.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.client_builder = self.client_builder.add_root_certificate(certificate);
+        self
+    }
+
+    /// Controls whether the built-in system root certificates are trusted.
+    pub fn tls_built_in_root_certs(mut self, enabled: bool) -> Self {
+        self.client_builder = self.client_builder.tls_built_in_root_certs(enabled);
+        self
+    }
+
+    /// Disables certificate validation.
+    ///
+    /// # Warning
+    /// This makes the connection vulnerable to man-in-the-middle attacks and should only be used for testing.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.client_builder = self.client_builder.danger_accept_invalid_certs(accept);
+        self
+    }
+
+    /// Only uses HTTP/2, without negotiating the protocol version first.
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.client_builder = self.client_builder.http2_prior_knowledge();
+        self
+    }
+
+    /// Only uses HTTP/1.
+    pub fn http1_only(mut self) -> Self {
+        self.client_builder = self.client_builder.http1_only();
+        self
+    }
+
+    /// Sets the policy used to retry failed requests. Defaults to [`RetryPolicy::default`].
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the encoding configuration of the network, which determines the Blob ID of stored data.
+    /// Defaults to [`EncodingConfig::default`], matching Walrus Mainnet and Testnet.
+    pub fn encoding_config(mut self, encoding_config: EncodingConfig) -> Self {
+        self.encoding_config = encoding_config;
+        self
+    }
+
+    /// Enables verified reads: Blobs read by Blob ID are checked against their Blob ID before they are returned.
+    ///
+    /// Verification recomputes the Blob ID from the received data, so it requires the whole Blob in memory
+    /// and costs CPU time; streaming reads buffer the Blob before yielding it. Reads by object ID and Quilt
+    /// patch reads cannot be verified. Disabled by default.
+    pub fn verify_reads(mut self, verify_reads: bool) -> Self {
+        self.verify_reads = verify_reads;
+        self
+    }
+
+    /// Sets an observer notified of the progress of uploads and downloads, e.g. to drive a progress bar.
+    pub fn progress_observer(mut self, observer: impl ProgressObserver + 'static) -> Self {
+        self.progress_observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Sets the path, relative to the Aggregator URLs, of an endpoint serving the [`NetworkInfo`] of the network.
+    ///
+    /// Walrus Aggregators and Publishers do not report epochs, limits and prices, and no public deployment offers
+    /// such an endpoint, so [`WalrusClient::network_info`] needs a service you run yourself, in the layout
+    /// printed by `walrus info --json`; for example, a proxy in front of the Aggregator that serves the cached
+    /// output of that command. Not set by default.
+    ///
+    /// [`NetworkInfo`]: crate::network::NetworkInfo
+    pub fn network_info_path(mut self, path: impl Into<String>) -> Self {
+        self.network_info_path = Some(path.into());
+        self
+    }
+
+    /// Sets the response headers reported as [`QuiltPatchResponse::tags`] of Quilt patch reads.
+    ///
+    /// Aggregators only return the tags named in their configured list of allowed headers, as headers of the
+    /// same name; set this to the same list to read further tags. Header names are case-insensitive. Defaults
+    /// to [`QuiltPatchResponse::DEFAULT_TAG_HEADERS`].
+    pub fn quilt_tag_headers(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.quilt_tag_headers = names
+            .into_iter()
+            .map(|name| name.into().to_ascii_lowercase())
+            .collect();
+        self
+    }
+
+    /// Builds the `WalrusClient`.
+    ///
+    /// # Returns
+    /// - `Ok(WalrusClient)`: Successfully created a client instance.
+    /// - `Err(WalrusError::InvalidUrl)`: If the provided URL is invalid.
+    /// - `Err(WalrusError::HttpRequest)`: If the HTTP client could not be built, e.g. due to an invalid TLS configuration.
+    pub fn build(self) -> Result<WalrusClient, WalrusError> {
+        let aggregator_urls = parse_urls(&self.aggregator_urls, "aggregator")?;
+        let publisher_urls = parse_urls(&self.publisher_urls, "publisher")?;
+        let pool = |urls| {
+            EndpointPool::new(
+                urls,
+                self.selection_strategy,
+                self.failure_threshold,
+                self.unhealthy_cooldown,
+            )
+        };
+
+        let http_client = match self.http_client {
+            Some(http_client) => http_client,
+            None => self
+                .client_builder
+                .default_headers(self.default_headers)
+                .build()?,
+        };
+
+        Ok(WalrusClient::from_parts(
+            pool(aggregator_urls),
+            pool(publisher_urls),
+            http_client,
+            self.retry_policy,
+            self.encoding_config,
+            self.verify_reads,
+            self.progress_observer,
+            self.network_info_path,
+            self.quilt_tag_headers,
+        ))
+    }
+}
+
+/// Parses the URLs of one kind of service.
+fn parse_urls(urls: &[String], service: &str) -> Result<Vec<Url>, WalrusError> {
+    urls.iter()
+        .map(|url| {
+            Url::parse(url)
+                .map_err(|e| WalrusError::InvalidUrl(format!("Invalid {service} URL: {e}")))
+        })
+        .collect()
+}
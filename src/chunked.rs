@@ -0,0 +1,201 @@
+use blake2::Blake2b;
+use blake2::digest::Digest;
+use blake2::digest::consts::U32;
+use serde::{Deserialize, Serialize};
+
+use crate::error::WalrusError;
+use crate::ids::BlobId;
+
+/// `ChunkedUploadOptions` controls how a large file is split into chunks by
+/// [`WalrusClient::store_file_chunked`](crate::client::WalrusClient::store_file_chunked).
+///
+/// # Example
+/// ```
+/// use walrus_rs::ChunkedUploadOptions;
+///
+/// let options = ChunkedUploadOptions::new().chunk_size(4 * 1024 * 1024).concurrency(8);
+/// assert!(options.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkedUploadOptions {
+    pub(crate) chunk_size: u64,
+    pub(crate) concurrency: usize,
+}
+
+impl Default for ChunkedUploadOptions {
+    /// Returns options with chunks of [`ChunkedUploadOptions::DEFAULT_CHUNK_SIZE`] bytes, 4 of which are uploaded
+    /// concurrently.
+    fn default() -> Self {
+        Self {
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            concurrency: 4,
+        }
+    }
+}
+
+impl ChunkedUploadOptions {
+    /// The default chunk size of 8 MiB, below the 10 MiB body limit of public Publishers.
+    pub const DEFAULT_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+    /// Creates new `ChunkedUploadOptions` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size of each chunk in bytes; the last chunk may be smaller. Must be greater than zero.
+    pub fn chunk_size(mut self, chunk_size: u64) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Sets the number of chunks uploaded concurrently. Must be greater than zero.
+    ///
+    /// Each chunk being uploaded is held in memory, so memory usage is about `concurrency * chunk_size`.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Checks that the options are consistent.
+    ///
+    /// # Returns
+    /// - `Ok(())`: The options are valid.
+    /// - `Err(WalrusError::InvalidParameter)`: If the chunk size or concurrency is zero, or a chunk does not fit in memory.
+    pub fn validate(&self) -> Result<(), WalrusError> {
+        if self.chunk_size == 0 || usize::try_from(self.chunk_size).is_err() {
+            return Err(WalrusError::InvalidParameter(format!(
+                "invalid chunk size {}",
+                self.chunk_size
+            )));
+        }
+        if self.concurrency == 0 {
+            return Err(WalrusError::InvalidParameter(
+                "concurrency must be greater than zero".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `ChunkManifest` lists the chunks a large file was split into, and is itself stored as a JSON Blob.
+///
+/// The Blob ID of the manifest identifies the whole file; it is read back with
+/// [`WalrusClient::read_chunked_blob`](crate::client::WalrusClient::read_chunked_blob).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkManifest {
+    /// The version of the manifest format.
+    pub version: u32,
+    /// The total size of the file in bytes.
+    pub size: u64,
+    /// The size of each chunk but the last in bytes.
+    pub chunk_size: u64,
+    /// The hash function used for the chunk hashes.
+    pub hash_algorithm: String,
+    /// The chunks, in file order.
+    pub chunks: Vec<ManifestChunk>,
+}
+
+/// A chunk listed in a [`ChunkManifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestChunk {
+    /// The Blob ID of the chunk.
+    pub blob_id: BlobId,
+    /// The size of the chunk in bytes.
+    pub size: u64,
+    /// The hex-encoded hash of the chunk data.
+    pub hash: String,
+}
+
+/// The result of storing a file with
+/// [`WalrusClient::store_file_chunked`](crate::client::WalrusClient::store_file_chunked).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkedStoreResult {
+    /// The Blob ID of the manifest, which identifies the whole file.
+    pub manifest_blob_id: BlobId,
+    /// The manifest listing the stored chunks.
+    pub manifest: ChunkManifest,
+}
+
+impl ChunkManifest {
+    /// The current version of the manifest format.
+    pub const VERSION: u32 = 1;
+
+    /// The hash function used for the chunk hashes.
+    pub const HASH_ALGORITHM: &'static str = "blake2b256";
+
+    /// Creates a manifest of the given chunks.
+    pub(crate) fn new(size: u64, chunk_size: u64, chunks: Vec<ManifestChunk>) -> Self {
+        Self {
+            version: Self::VERSION,
+            size,
+            chunk_size,
+            hash_algorithm: Self::HASH_ALGORITHM.to_string(),
+            chunks,
+        }
+    }
+
+    /// Parses a manifest from the data of a manifest Blob.
+    ///
+    /// # Returns
+    /// - `Ok(ChunkManifest)`: The manifest.
+    /// - `Err(WalrusError::ParseError)`: If the data is not a manifest of a supported version.
+    /// - `Err(WalrusError::ManifestMismatch)`: If the chunk sizes do not add up to the total size.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, WalrusError> {
+        let manifest: Self = serde_json::from_slice(data)
+            .map_err(|e| WalrusError::ParseError(format!("Failed to parse chunk manifest: {e}")))?;
+        if manifest.version != Self::VERSION || manifest.hash_algorithm != Self::HASH_ALGORITHM {
+            return Err(WalrusError::ParseError(format!(
+                "Unsupported chunk manifest version {} with hash algorithm {:?}",
+                manifest.version, manifest.hash_algorithm
+            )));
+        }
+        let total = manifest
+            .chunks
+            .iter()
+            .try_fold(0u64, |total, chunk| total.checked_add(chunk.size));
+        if total != Some(manifest.size) {
+            return Err(WalrusError::ManifestMismatch(format!(
+                "chunk sizes do not add up to the total size of {} bytes",
+                manifest.size
+            )));
+        }
+        Ok(manifest)
+    }
+
+    /// Serializes the manifest to the data of a manifest Blob.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("a chunk manifest is always serializable")
+    }
+}
+
+impl ManifestChunk {
+    /// Describes a chunk with the given data, stored under the given Blob ID.
+    pub(crate) fn new(blob_id: BlobId, data: &[u8]) -> Self {
+        Self {
+            blob_id,
+            size: data.len() as u64,
+            hash: hash_hex(data),
+        }
+    }
+
+    /// Checks that the data read for the chunk with the given index matches the manifest.
+    pub(crate) fn verify(&self, index: usize, data: &[u8]) -> Result<(), WalrusError> {
+        if data.len() as u64 != self.size || hash_hex(data) != self.hash {
+            return Err(WalrusError::ManifestMismatch(format!(
+                "chunk {index} with Blob ID {} has unexpected content",
+                self.blob_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the hex-encoded Blake2b-256 hash of the data.
+pub(crate) fn hash_hex(data: &[u8]) -> String {
+    Blake2b::<U32>::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
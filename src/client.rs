@@ -1,18 +1,66 @@
+use std::io::{self, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt, TryStreamExt};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
 use reqwest::{
-    Client, Url,
+    Body, Client, Request, RequestBuilder, Response, StatusCode, Url,
+    header::{CONTENT_LENGTH, CONTENT_RANGE, RANGE},
     multipart::{Form, Part},
 };
 use serde_json::to_string;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
 
-use crate::error::WalrusError;
-use crate::models::{BlobMetadata, BlobStoreResult, QuiltMetadata, QuiltStoreResponse};
+use crate::builder::WalrusClientBuilder;
+use crate::chunked::{ChunkManifest, ChunkedStoreResult, ChunkedUploadOptions, ManifestChunk};
+use crate::encoding::EncodingConfig;
+use crate::endpoint::EndpointPool;
+use crate::error::{ApiError, RequestKind, WalrusError};
+use crate::ids::{BlobId, ObjectId, QuiltPatchId};
+use crate::models::{
+    BlobMetadata, BlobStoreResult, QuiltMetadata, QuiltPatchItem, QuiltPatchResponse,
+    QuiltStoreResponse,
+};
+use crate::network::{NetworkInfo, StoreCostEstimate};
+use crate::options::StoreOptions;
+use crate::progress::{ProgressObserver, ProgressTracker};
+use crate::quilt_dir::{
+    QuiltDirOptions, identifier_path, modified_time, plan_quilts, verify_patch,
+};
+use crate::range::ByteRange;
+use crate::retry::{Idempotency, RetryPolicy};
+use crate::stream::BlobStream;
 
 /// `WalrusClient` is an asynchronous Walrus API client.
 /// It encapsulates all logic for interacting with the Walrus Aggregator and Publisher services.
+///
+/// Requests are spread over pools of Aggregator and Publisher endpoints. Endpoints that fail repeatedly are
+/// marked unhealthy and skipped for a cool-down period, and failed requests transparently fail over to the
+/// next healthy endpoint.
+///
+/// With verified reads enabled (see [`WalrusClientBuilder::verify_reads`]), Blobs read by Blob ID are checked
+/// against their Blob ID, so that a faulty Aggregator cannot silently serve wrong content.
+///
+/// A [`ProgressObserver`] set with [`WalrusClientBuilder::progress_observer`] is notified as data is uploaded
+/// and downloaded.
+///
+/// Cloning a client is cheap: clones share the HTTP connection pool and the health of the endpoints, so a
+/// client can be kept in the state of a web server or moved into spawned tasks without wrapping it in an `Arc`.
+#[derive(Clone)]
 pub struct WalrusClient {
-    aggregator_url: Url,
-    publisher_url: Url,
+    aggregators: Arc<EndpointPool>,
+    publishers: Arc<EndpointPool>,
     http_client: Client,
+    retry_policy: Arc<RetryPolicy>,
+    encoding_config: EncodingConfig,
+    verify_reads: bool,
+    progress_observer: Option<Arc<dyn ProgressObserver>>,
+    network_info_path: Option<Arc<str>>,
+    quilt_tag_headers: Arc<[String]>,
 }
 
 impl WalrusClient {
@@ -26,26 +74,62 @@ impl WalrusClient {
     /// - `Ok(WalrusClient)`: Successfully created a client instance.
     /// - `Err(WalrusError::InvalidUrl)`: If the provided URL is invalid.
     pub fn new(aggregator_url: &str, publisher_url: &str) -> Result<Self, WalrusError> {
-        let aggregator_url = Url::parse(aggregator_url)
-            .map_err(|e| WalrusError::InvalidUrl(format!("Invalid aggregator URL: {e}")))?;
-        let publisher_url = Url::parse(publisher_url)
-            .map_err(|e| WalrusError::InvalidUrl(format!("Invalid publisher URL: {e}")))?;
-
-        Ok(Self {
-            aggregator_url,
-            publisher_url,
-            http_client: Client::new(),
-        })
+        Self::builder(aggregator_url, publisher_url).build()
+    }
+
+    /// Creates a [`WalrusClientBuilder`] to configure a `WalrusClient`.
+    ///
+    /// # Arguments
+    /// - `aggregator_url`: The URL string for the Walrus Aggregator service.
+    /// - `publisher_url`: The URL string for the Walrus Publisher service.
+    pub fn builder(aggregator_url: &str, publisher_url: &str) -> WalrusClientBuilder {
+        WalrusClientBuilder::new(aggregator_url, publisher_url)
+    }
+
+    /// Assembles a client from already validated parts.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn from_parts(
+        aggregators: EndpointPool,
+        publishers: EndpointPool,
+        http_client: Client,
+        retry_policy: RetryPolicy,
+        encoding_config: EncodingConfig,
+        verify_reads: bool,
+        progress_observer: Option<Arc<dyn ProgressObserver>>,
+        network_info_path: Option<String>,
+        quilt_tag_headers: Vec<String>,
+    ) -> Self {
+        Self {
+            aggregators: Arc::new(aggregators),
+            publishers: Arc::new(publishers),
+            http_client,
+            retry_policy: Arc::new(retry_policy),
+            encoding_config,
+            verify_reads,
+            progress_observer,
+            network_info_path: network_info_path.map(Arc::from),
+            quilt_tag_headers: Arc::from(quilt_tag_headers),
+        }
     }
 
-    /// Returns the URL of the Aggregator service.
+    /// Returns the URL of the primary Aggregator service.
     pub fn aggregator_url(&self) -> &Url {
-        &self.aggregator_url
+        self.aggregators.primary()
     }
 
-    /// Returns the URL of the Publisher service.
+    /// Returns the URL of the primary Publisher service.
     pub fn publisher_url(&self) -> &Url {
-        &self.publisher_url
+        self.publishers.primary()
+    }
+
+    /// Returns the pool of Aggregator endpoints.
+    pub fn aggregators(&self) -> &EndpointPool {
+        &self.aggregators
+    }
+
+    /// Returns the pool of Publisher endpoints.
+    pub fn publishers(&self) -> &EndpointPool {
+        &self.publishers
     }
 
     /// Returns a reference to the internal `reqwest::Client` instance.
@@ -53,54 +137,262 @@ impl WalrusClient {
         &self.http_client
     }
 
+    /// Returns the retry policy applied to all requests.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Returns the encoding configuration used to compute Blob IDs.
+    pub fn encoding_config(&self) -> &EncodingConfig {
+        &self.encoding_config
+    }
+
+    /// Indicates if Blobs read by Blob ID are verified against their Blob ID.
+    pub fn verifies_reads(&self) -> bool {
+        self.verify_reads
+    }
+
+    /// Sends a request to a pool of endpoints, failing over between endpoints and retrying transient failures
+    /// according to the client's [`RetryPolicy`].
+    ///
+    /// Each round tries the endpoints of the pool in the order chosen by its selection strategy, moving on to the
+    /// next endpoint on retryable failures. Rounds are repeated with backoff up to the policy's maximum number of attempts.
+    ///
+    /// Aggregators may lag behind the network, so a `404 Not Found` from one of them also moves on to the next
+    /// Aggregator of the round; if none of them has the data, the `404 Not Found` is returned without starting
+    /// another round. Other non-retryable failures are returned immediately.
+    ///
+    /// `next_request` is called with the target URL for every try and returns `None` if the request cannot be
+    /// repeated, e.g. because its body is a stream that cannot be cloned.
+    async fn send(
+        &self,
+        kind: RequestKind,
+        idempotency: Idempotency,
+        path: &str,
+        query: &[(&str, String)],
+        mut next_request: impl FnMut(Url) -> Option<RequestBuilder>,
+    ) -> Result<Response, WalrusError> {
+        let (pool, reads) = match kind {
+            RequestKind::StoreBlob | RequestKind::StoreQuilt | RequestKind::PublisherStatus => {
+                (&self.publishers, false)
+            }
+            RequestKind::ReadBlob
+            | RequestKind::ReadQuiltPatch
+            | RequestKind::BlobMetadata
+            | RequestKind::ListQuiltPatches
+            | RequestKind::Status => (&self.aggregators, true),
+        };
+
+        let mut last_error = None;
+        for round in 1..=self.retry_policy.attempt_limit() {
+            let mut not_found = None;
+            if round > 1 {
+                tokio::time::sleep(self.retry_policy.backoff(round - 1)).await;
+            }
+
+            for index in pool.candidates() {
+                let url = endpoint_url(pool.url(index), path, query)?;
+                let Some(request) = next_request(url) else {
+                    return Err(last_error.unwrap_or_else(|| {
+                        WalrusError::Other("Failed to build request".to_string())
+                    }));
+                };
+
+                let started = Instant::now();
+                let (transient, retryable, error) = match request.send().await {
+                    Ok(response) if response.status().is_success() => {
+                        pool.record_success(index, started.elapsed());
+                        return Ok(response);
+                    }
+                    Ok(response) => {
+                        let status = response.status();
+                        let policy = &self.retry_policy;
+                        (
+                            policy.is_retryable_status(status, Idempotency::Idempotent),
+                            policy.is_retryable_status(status, idempotency),
+                            ApiError::from_response(kind, response).await.into(),
+                        )
+                    }
+                    Err(error) => (
+                        self.retry_policy
+                            .is_retryable_error(&error, Idempotency::Idempotent),
+                        self.retry_policy.is_retryable_error(&error, idempotency),
+                        WalrusError::from(error),
+                    ),
+                };
+
+                if transient {
+                    pool.record_failure(index);
+                }
+                if !retryable {
+                    if reads && is_not_found(&error) {
+                        not_found = Some(error);
+                        continue;
+                    }
+                    return Err(error);
+                }
+                last_error = Some(error);
+            }
+
+            if let Some(error) = not_found {
+                return Err(error);
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| WalrusError::Other("No endpoint available".to_string())))
+    }
+
     /// Stores a Blob to the Walrus Publisher service.
     ///
     /// # Arguments
     /// - `data`: The Blob data to store, can be any type convertible to `reqwest::Body`.
-    /// - `epochs`: Optional, the number of epochs for the Blob's lifecycle.
-    /// - `deletable`: Optional, indicates if the Blob is deletable.
-    /// - `permanent`: Optional, indicates if the Blob is permanently stored.
-    /// - `send_object_to`: Optional, specifies where to send the object.
+    ///   Upload progress is only reported for in-memory data, not for streaming bodies.
+    /// - `options`: The [`StoreOptions`] controlling how the Blob is stored.
     ///
     /// # Returns
     /// - `Ok(BlobStoreResult)`: Successfully stored the Blob and returned the result.
+    /// - `Err(WalrusError::InvalidParameter)`: If the store options are invalid.
     /// - `Err(WalrusError)`: If storing failed, possibly due to invalid URL, network error, or response parsing failure.
     pub async fn store_blob(
         &self,
         data: impl Into<reqwest::Body>,
-        epochs: Option<u64>,
-        deletable: Option<bool>,
-        permanent: Option<bool>,
-        send_object_to: Option<&str>,
+        options: &StoreOptions,
     ) -> Result<BlobStoreResult, WalrusError> {
-        let mut url = self
-            .publisher_url()
-            .join("v1/blobs")
-            .map_err(|e| WalrusError::InvalidUrl(format!("Failed to build URL: {e}")))?;
-
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            if let Some(e) = epochs {
-                query_pairs.append_pair("epochs", &e.to_string());
-            }
-            if let Some(d) = deletable {
-                query_pairs.append_pair("deletable", &d.to_string());
-            }
-            if let Some(p) = permanent {
-                query_pairs.append_pair("permanent", &p.to_string());
-            }
-            if let Some(s) = send_object_to {
-                query_pairs.append_pair("send_object_to", s);
-            }
+        let data: Body = data.into();
+        let length = data.as_bytes().map(|bytes| bytes.len() as u64);
+        if let (Some(tracker), Some(bytes)) = (
+            self.progress_tracker(RequestKind::StoreBlob, length),
+            data.as_bytes(),
+        ) {
+            // Splitting the data into chunks lets progress be reported while it is sent.
+            let bytes = Bytes::copy_from_slice(bytes);
+            return self
+                .put_blob(options, |url| {
+                    tracker.restart();
+                    Some(
+                        self.http_client()
+                            .put(url)
+                            .header(CONTENT_LENGTH, bytes.len())
+                            .body(tracker.upload_body(bytes.clone())),
+                    )
+                })
+                .await;
         }
 
-        let response = self
+        let request = self
             .http_client()
-            .put(url)
+            .put(self.publisher_url().clone())
             .body(data)
-            .send()
-            .await?
-            .error_for_status()?;
+            .build()?;
+
+        self.put_blob(options, cloning(self.http_client(), request))
+            .await
+    }
+
+    /// Stores the contents of a file as a Blob to the Walrus Publisher service.
+    ///
+    /// The file is streamed to the Publisher with its length as `Content-Length`, without loading it into memory.
+    /// The file is reopened if the request is retried.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to store.
+    /// - `options`: The [`StoreOptions`] controlling how the Blob is stored.
+    ///
+    /// # Returns
+    /// - `Ok(BlobStoreResult)`: Successfully stored the Blob and returned the result.
+    /// - `Err(WalrusError)`: If storing failed, possibly due to an unreadable file, network error, or response parsing failure.
+    pub async fn store_blob_from_path(
+        &self,
+        path: impl AsRef<Path>,
+        options: &StoreOptions,
+    ) -> Result<BlobStoreResult, WalrusError> {
+        let path = path.as_ref().to_path_buf();
+        let file = tokio::fs::File::open(&path).await?;
+        let length = file.metadata().await?.len();
+        let tracker = self.progress_tracker(RequestKind::StoreBlob, Some(length));
+
+        let mut opened = Some(file);
+        self.put_blob(options, |url| {
+            // The first attempt sends the file opened above; retries reopen it once their body is polled.
+            let stream = match opened.take() {
+                Some(file) => ReaderStream::new(file.take(length)).left_stream(),
+                None => {
+                    let path = path.clone();
+                    futures_util::stream::once(tokio::fs::File::open(path))
+                        .map_ok(move |file| ReaderStream::new(file.take(length)))
+                        .try_flatten()
+                        .right_stream()
+                }
+            };
+            Some(
+                self.http_client()
+                    .put(url)
+                    .header(CONTENT_LENGTH, length)
+                    .body(observed_body(tracker.as_ref(), stream)),
+            )
+        })
+        .await
+    }
+
+    /// Stores data read from an asynchronous reader as a Blob to the Walrus Publisher service.
+    ///
+    /// Exactly `length` bytes are streamed from the reader to the Publisher, which must be known in advance
+    /// as it is sent as `Content-Length`. Since the reader cannot be rewound, the request is not retried.
+    ///
+    /// # Arguments
+    /// - `reader`: The reader providing the Blob data.
+    /// - `length`: The number of bytes to read and store.
+    /// - `options`: The [`StoreOptions`] controlling how the Blob is stored.
+    ///
+    /// # Returns
+    /// - `Ok(BlobStoreResult)`: Successfully stored the Blob and returned the result.
+    /// - `Err(WalrusError)`: If storing failed, possibly due to a read error, network error, or response parsing failure.
+    pub async fn store_blob_from_reader(
+        &self,
+        reader: impl AsyncRead + Send + 'static,
+        length: u64,
+        options: &StoreOptions,
+    ) -> Result<BlobStoreResult, WalrusError> {
+        let stream = ReaderStream::new(Box::pin(reader.take(length)));
+        self.store_blob_from_stream(stream, length, options).await
+    }
+
+    /// Stores a stream of `length` bytes as a Blob to the Walrus Publisher service, sending it exactly once.
+    pub(crate) async fn store_blob_from_stream(
+        &self,
+        stream: impl Stream<Item = Result<Bytes, io::Error>> + Send + 'static,
+        length: u64,
+        options: &StoreOptions,
+    ) -> Result<BlobStoreResult, WalrusError> {
+        let tracker = self.progress_tracker(RequestKind::StoreBlob, Some(length));
+        let mut stream = Some(stream);
+        self.put_blob(options, |url| {
+            Some(
+                self.http_client()
+                    .put(url)
+                    .header(CONTENT_LENGTH, length)
+                    .body(observed_body(tracker.as_ref(), stream.take()?)),
+            )
+        })
+        .await
+    }
+
+    /// Sends a Blob store request built by `next_request` and parses the result.
+    async fn put_blob(
+        &self,
+        options: &StoreOptions,
+        next_request: impl FnMut(Url) -> Option<RequestBuilder>,
+    ) -> Result<BlobStoreResult, WalrusError> {
+        options.validate()?;
+        let response = self
+            .send(
+                RequestKind::StoreBlob,
+                options.idempotency(),
+                "v1/blobs",
+                &options.query_pairs(),
+                next_request,
+            )
+            .await?;
 
         let result: BlobStoreResult = response.json().await.map_err(|e| {
             WalrusError::ParseError(format!("Failed to parse BlobStoreResult: {e}"))
@@ -116,26 +408,85 @@ impl WalrusClient {
     ///
     /// # Returns
     /// - `Ok(Vec<u8>)`: Successfully read the Blob data.
+    /// - `Err(WalrusError::BlobIdMismatch)`: If reads are verified and the data does not match the Blob ID.
     /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
-    pub async fn read_blob_by_id(&self, blob_id: &str) -> Result<Vec<u8>, WalrusError> {
-        let url = self
-            .aggregator_url()
-            .join(&format!("v1/blobs/{blob_id}"))
-            .map_err(|e| WalrusError::InvalidUrl(format!("Failed to build URL: {e}")))?;
-
+    pub async fn read_blob_by_id(&self, blob_id: &BlobId) -> Result<Vec<u8>, WalrusError> {
+        let path = format!("v1/blobs/{blob_id}");
         let response = self
-            .http_client()
-            .get(url)
-            .send()
-            .await?
-            .error_for_status()?;
+            .send(
+                RequestKind::ReadBlob,
+                Idempotency::Idempotent,
+                &path,
+                &[],
+                |url| Some(self.http_client().get(url)),
+            )
+            .await?;
 
-        let bytes = response
-            .bytes()
-            .await
-            .map_err(|e| WalrusError::ParseError(format!("Failed to read blob bytes: {e}")))?;
+        let data = self
+            .response_stream(RequestKind::ReadBlob, response)
+            .collect_bytes()
+            .await?;
 
-        Ok(bytes.to_vec())
+        self.verified(blob_id, data).await
+    }
+
+    /// Streams Blob data by Blob ID from the Walrus Aggregator service.
+    ///
+    /// Unlike [`WalrusClient::read_blob_by_id`], the data is not buffered in memory but returned chunk by chunk
+    /// as it is received. If reads are verified, the whole Blob is buffered and verified before it is yielded.
+    ///
+    /// # Arguments
+    /// - `blob_id`: The unique identifier of the Blob.
+    ///
+    /// # Returns
+    /// - `Ok(BlobStream)`: The stream of Blob data.
+    /// - `Err(WalrusError::BlobIdMismatch)`: If reads are verified and the data does not match the Blob ID.
+    /// - `Err(WalrusError)`: If the request failed, possibly due to invalid URL or network error.
+    pub async fn read_blob_stream_by_id(
+        &self,
+        blob_id: &BlobId,
+    ) -> Result<BlobStream, WalrusError> {
+        let stream = self
+            .get_stream(RequestKind::ReadBlob, &format!("v1/blobs/{blob_id}"))
+            .await?;
+        if !self.verify_reads {
+            return Ok(stream);
+        }
+        let data = self
+            .verified(blob_id, stream.collect_bytes().await?)
+            .await?;
+        let length = data.len() as u64;
+        Ok(BlobStream::new(
+            futures_util::stream::once(async move { Ok(Bytes::from(data)) }),
+            Some(length),
+        ))
+    }
+
+    /// Reads a byte range of a Blob by Blob ID from the Walrus Aggregator service.
+    ///
+    /// The range is requested with an HTTP `Range` header. If the Aggregator ignores it and sends the whole Blob,
+    /// the range is sliced out of the response, which is only read up to the end of the range.
+    /// Range reads are not verified, even if verified reads are enabled.
+    ///
+    /// # Arguments
+    /// - `blob_id`: The unique identifier of the Blob.
+    /// - `range`: The bytes to read, e.g. `0..1024` or [`ByteRange::suffix`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes; fewer than requested if the range extends beyond the end of the data.
+    /// - `Err(WalrusError::InvalidParameter)`: If the range is empty.
+    /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
+    pub async fn read_blob_range(
+        &self,
+        blob_id: &BlobId,
+        range: impl Into<ByteRange>,
+    ) -> Result<Vec<u8>, WalrusError> {
+        self.get_range(
+            RequestKind::ReadBlob,
+            &format!("v1/blobs/{blob_id}"),
+            range.into(),
+        )
+        .await
     }
 
     /// Reads Blob data by object ID from the Walrus Aggregator service.
@@ -146,25 +497,198 @@ impl WalrusClient {
     /// # Returns
     /// - `Ok(Vec<u8>)`: Successfully read the Blob data.
     /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
-    pub async fn read_blob_by_object_id(&self, object_id: &str) -> Result<Vec<u8>, WalrusError> {
-        let url = self
-            .aggregator_url()
-            .join(&format!("v1/blobs/by-object-id/{object_id}"))
-            .map_err(|e| WalrusError::InvalidUrl(format!("Failed to build URL: {e}")))?;
-
+    pub async fn read_blob_by_object_id(
+        &self,
+        object_id: &ObjectId,
+    ) -> Result<Vec<u8>, WalrusError> {
+        let path = format!("v1/blobs/by-object-id/{object_id}");
         let response = self
-            .http_client()
-            .get(url)
-            .send()
-            .await?
-            .error_for_status()?;
+            .send(
+                RequestKind::ReadBlob,
+                Idempotency::Idempotent,
+                &path,
+                &[],
+                |url| Some(self.http_client().get(url)),
+            )
+            .await?;
 
-        let bytes = response
-            .bytes()
+        self.response_stream(RequestKind::ReadBlob, response)
+            .collect_bytes()
             .await
-            .map_err(|e| WalrusError::ParseError(format!("Failed to read blob bytes: {e}")))?;
+    }
+
+    /// Streams Blob data by object ID from the Walrus Aggregator service.
+    ///
+    /// # Arguments
+    /// - `object_id`: The unique identifier of the object.
+    ///
+    /// # Returns
+    /// - `Ok(BlobStream)`: The stream of Blob data.
+    /// - `Err(WalrusError)`: If the request failed, possibly due to invalid URL or network error.
+    pub async fn read_blob_stream_by_object_id(
+        &self,
+        object_id: &ObjectId,
+    ) -> Result<BlobStream, WalrusError> {
+        self.get_stream(
+            RequestKind::ReadBlob,
+            &format!("v1/blobs/by-object-id/{object_id}"),
+        )
+        .await
+    }
 
-        Ok(bytes.to_vec())
+    /// Reads a byte range of a Blob by object ID from the Walrus Aggregator service.
+    ///
+    /// See [`WalrusClient::read_blob_range`] for how the range is read.
+    ///
+    /// # Arguments
+    /// - `object_id`: The unique identifier of the object.
+    /// - `range`: The bytes to read, e.g. `0..1024` or [`ByteRange::suffix`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes; fewer than requested if the range extends beyond the end of the data.
+    /// - `Err(WalrusError::InvalidParameter)`: If the range is empty.
+    /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
+    pub async fn read_blob_range_by_object_id(
+        &self,
+        object_id: &ObjectId,
+        range: impl Into<ByteRange>,
+    ) -> Result<Vec<u8>, WalrusError> {
+        self.get_range(
+            RequestKind::ReadBlob,
+            &format!("v1/blobs/by-object-id/{object_id}"),
+            range.into(),
+        )
+        .await
+    }
+
+    /// Stores a large file as chunks, plus a manifest Blob listing them.
+    ///
+    /// The file is split into chunks of [`ChunkedUploadOptions::chunk_size`] bytes, which are stored concurrently
+    /// as separate Blobs with [`WalrusClient::store_blob`]. A [`ChunkManifest`] with the Blob ID, size and hash of
+    /// each chunk is then stored as a JSON Blob, whose Blob ID identifies the whole file.
+    /// If storing a chunk fails, the chunks stored so far are not deleted.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to store.
+    /// - `chunked_options`: The [`ChunkedUploadOptions`] controlling how the file is split.
+    /// - `options`: The [`StoreOptions`] used for the chunks and the manifest.
+    ///
+    /// # Returns
+    /// - `Ok(ChunkedStoreResult)`: The Blob ID of the manifest, together with the manifest.
+    /// - `Err(WalrusError::InvalidParameter)`: If the options are invalid.
+    /// - `Err(WalrusError)`: If reading the file or storing a chunk or the manifest failed.
+    pub async fn store_file_chunked(
+        &self,
+        path: impl AsRef<Path>,
+        chunked_options: &ChunkedUploadOptions,
+        options: &StoreOptions,
+    ) -> Result<ChunkedStoreResult, WalrusError> {
+        chunked_options.validate()?;
+        options.validate()?;
+        let path = path.as_ref();
+        let size = tokio::fs::metadata(path).await?.len();
+        let chunk_size = chunked_options.chunk_size;
+
+        let chunks = futures_util::stream::iter((0..size).step_by(chunk_size as usize))
+            .map(|offset| async move {
+                let mut data = vec![0; chunk_size.min(size - offset) as usize];
+                let mut file = tokio::fs::File::open(path).await?;
+                file.seek(SeekFrom::Start(offset)).await?;
+                file.read_exact(&mut data).await?;
+
+                let data = Bytes::from(data);
+                let result = self.store_blob(data.clone(), options).await?;
+                Ok::<_, WalrusError>(ManifestChunk::new(stored_blob_id(&result)?, &data))
+            })
+            .buffered(chunked_options.concurrency)
+            .try_collect()
+            .await?;
+
+        let manifest = ChunkManifest::new(size, chunk_size, chunks);
+        let result = self.store_blob(manifest.to_bytes(), options).await?;
+        Ok(ChunkedStoreResult {
+            manifest_blob_id: stored_blob_id(&result)?,
+            manifest,
+        })
+    }
+
+    /// Reads the [`ChunkManifest`] of a file stored with [`WalrusClient::store_file_chunked`].
+    ///
+    /// # Arguments
+    /// - `manifest_blob_id`: The Blob ID of the manifest.
+    ///
+    /// # Returns
+    /// - `Ok(ChunkManifest)`: The manifest.
+    /// - `Err(WalrusError::ParseError)`: If the Blob is not a chunk manifest.
+    /// - `Err(WalrusError)`: If reading failed.
+    pub async fn read_chunk_manifest(
+        &self,
+        manifest_blob_id: &BlobId,
+    ) -> Result<ChunkManifest, WalrusError> {
+        ChunkManifest::from_bytes(&self.read_blob_by_id(manifest_blob_id).await?)
+    }
+
+    /// Reads a file stored with [`WalrusClient::store_file_chunked`] and reassembles it in memory.
+    ///
+    /// # Arguments
+    /// - `manifest_blob_id`: The Blob ID of the manifest.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The file data.
+    /// - `Err(WalrusError::ManifestMismatch)`: If a chunk does not match its size or hash in the manifest.
+    /// - `Err(WalrusError)`: If reading the manifest or a chunk failed.
+    pub async fn read_chunked_blob(
+        &self,
+        manifest_blob_id: &BlobId,
+    ) -> Result<Vec<u8>, WalrusError> {
+        let mut data = Vec::new();
+        self.read_chunked_blob_to_writer(manifest_blob_id, &mut data)
+            .await?;
+        Ok(data)
+    }
+
+    /// Reads a file stored with [`WalrusClient::store_file_chunked`] and writes it to `writer` chunk by chunk.
+    ///
+    /// A few chunks are fetched ahead concurrently; each chunk is checked against the manifest before it is
+    /// written, so only verified data reaches the writer.
+    ///
+    /// # Arguments
+    /// - `manifest_blob_id`: The Blob ID of the manifest.
+    /// - `writer`: The destination of the file data.
+    ///
+    /// # Returns
+    /// - `Ok(u64)`: The number of bytes written.
+    /// - `Err(WalrusError::ManifestMismatch)`: If a chunk does not match its size or hash in the manifest.
+    /// - `Err(WalrusError)`: If reading the manifest or a chunk, or writing failed.
+    pub async fn read_chunked_blob_to_writer<W: AsyncWrite + Unpin>(
+        &self,
+        manifest_blob_id: &BlobId,
+        writer: &mut W,
+    ) -> Result<u64, WalrusError> {
+        let manifest = self.read_chunk_manifest(manifest_blob_id).await?;
+        let mut chunks = std::pin::pin!(self.read_chunks(&manifest));
+        let mut written = 0;
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+
+    /// Reads the chunks listed in a manifest in order, fetching a few chunks ahead and verifying each one.
+    pub(crate) fn read_chunks<'a>(
+        &'a self,
+        manifest: &'a ChunkManifest,
+    ) -> impl Stream<Item = Result<Vec<u8>, WalrusError>> + 'a {
+        futures_util::stream::iter(manifest.chunks.iter().enumerate())
+            .map(move |(index, chunk)| async move {
+                let data = self.read_blob_by_id(&chunk.blob_id).await?;
+                chunk.verify(index, &data)?;
+                Ok(data)
+            })
+            .buffered(CHUNK_READ_AHEAD)
     }
 
     /// Stores a Quilt (multiple files) to the Walrus Publisher service.
@@ -172,63 +696,66 @@ impl WalrusClient {
     /// # Arguments
     /// - `files`: A vector of tuples containing the filename and file content.
     /// - `metadata`: Optional, metadata for the Quilt.
-    /// - `epochs`: Optional, the number of epochs for the Quilt's lifecycle.
-    /// - `deletable`: Optional, indicates if the Quilt is deletable.
-    /// - `permanent`: Optional, indicates if the Quilt is permanently stored.
-    /// - `send_object_to`: Optional, specifies where to send the object.
+    /// - `options`: The [`StoreOptions`] controlling how the Quilt is stored.
     ///
     /// # Returns
     /// - `Ok(QuiltStoreResponse)`: Successfully stored the Quilt and returned the result.
+    /// - `Err(WalrusError::InvalidParameter)`: If the store options are invalid.
     /// - `Err(WalrusError)`: If storing failed, possibly due to invalid URL, network error, metadata serialization failure, or response parsing failure.
     pub async fn store_quilt(
         &self,
         files: Vec<(&str, Vec<u8>)>,
         metadata: Option<Vec<QuiltMetadata>>,
-        epochs: Option<u64>,
-        deletable: Option<bool>,
-        permanent: Option<bool>,
-        send_object_to: Option<&str>,
+        options: &StoreOptions,
     ) -> Result<QuiltStoreResponse, WalrusError> {
-        let mut url = self
-            .publisher_url()
-            .join("v1/quilts")
-            .map_err(|e| WalrusError::InvalidUrl(format!("Failed to build URL: {e}")))?;
-
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            if let Some(e) = epochs {
-                query_pairs.append_pair("epochs", &e.to_string());
-            }
-            if let Some(d) = deletable {
-                query_pairs.append_pair("deletable", &d.to_string());
+        options.validate()?;
+
+        let files: Vec<(String, Bytes)> = files
+            .into_iter()
+            .map(|(identifier, data)| (identifier.to_string(), Bytes::from(data)))
+            .collect();
+        let metadata_json = metadata
+            .map(|meta| {
+                to_string(&meta).map_err(|e| {
+                    WalrusError::ParseError(format!("Failed to serialize metadata: {e}"))
+                })
+            })
+            .transpose()?;
+
+        let total = files.iter().map(|(_, data)| data.len() as u64).sum();
+        let tracker = self.progress_tracker(RequestKind::StoreQuilt, Some(total));
+
+        // The multipart body is a stream that cannot be cloned, so it is rebuilt for every attempt.
+        let build_form = || {
+            if let Some(tracker) = &tracker {
+                tracker.restart();
             }
-            if let Some(p) = permanent {
-                query_pairs.append_pair("permanent", &p.to_string());
+            let mut form = Form::new();
+            for (identifier, data) in &files {
+                let body = match &tracker {
+                    Some(tracker) => tracker.upload_body(data.clone()),
+                    None => Body::from(data.clone()),
+                };
+                form = form.part(
+                    identifier.clone(),
+                    Part::stream_with_length(body, data.len() as u64),
+                );
             }
-            if let Some(s) = send_object_to {
-                query_pairs.append_pair("send_object_to", s);
+            if let Some(metadata_json) = &metadata_json {
+                form = form.part("_metadata", Part::text(metadata_json.clone()));
             }
-        }
-
-        let mut form = Form::new();
-        for (identifier, data) in files {
-            form = form.part(identifier.to_string(), Part::bytes(data));
-        }
-
-        if let Some(meta) = metadata {
-            let metadata_json = to_string(&meta).map_err(|e| {
-                WalrusError::ParseError(format!("Failed to serialize metadata: {e}"))
-            })?;
-            form = form.part("_metadata", Part::text(metadata_json));
-        }
+            form
+        };
 
         let response = self
-            .http_client()
-            .put(url)
-            .multipart(form)
-            .send()
-            .await?
-            .error_for_status()?;
+            .send(
+                RequestKind::StoreQuilt,
+                options.idempotency(),
+                "v1/quilts",
+                &options.query_pairs(),
+                |url| Some(self.http_client().put(url).multipart(build_form())),
+            )
+            .await?;
 
         let result: QuiltStoreResponse = response.json().await.map_err(|e| {
             WalrusError::ParseError(format!("Failed to parse QuiltStoreResponse: {e}"))
@@ -237,6 +764,103 @@ impl WalrusClient {
         Ok(result)
     }
 
+    /// Stores the files of a directory as one or more Quilts.
+    ///
+    /// The directory is walked recursively, and every file selected by the [`QuiltDirOptions`] is stored with its
+    /// path relative to the directory as identifier, using `/` as separator. Each file is tagged with its MIME type,
+    /// size, modification time and hash (see [`quilt_dir`](crate::quilt_dir) for the tag names).
+    ///
+    /// Files are sorted by identifier and stored in as few Quilts as the limits of the options allow, one Quilt at
+    /// a time. If storing a Quilt fails, the Quilts stored so far are not deleted.
+    ///
+    /// # Arguments
+    /// - `path`: The directory to store.
+    /// - `dir_options`: The [`QuiltDirOptions`] selecting the files and limiting the Quilts.
+    /// - `options`: The [`StoreOptions`] used for every Quilt.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<QuiltStoreResponse>)`: The results of storing the Quilts, in identifier order.
+    /// - `Err(WalrusError::InvalidParameter)`: If the options are invalid, no file is selected, or a file exceeds
+    ///   the maximum Quilt size.
+    /// - `Err(WalrusError)`: If reading the directory or storing a Quilt failed.
+    pub async fn store_quilt_from_dir(
+        &self,
+        path: impl AsRef<Path>,
+        dir_options: &QuiltDirOptions,
+        options: &StoreOptions,
+    ) -> Result<Vec<QuiltStoreResponse>, WalrusError> {
+        options.validate()?;
+        let batches = plan_quilts(path.as_ref(), dir_options, &self.encoding_config).await?;
+
+        let mut results = Vec::with_capacity(batches.len());
+        for batch in batches {
+            let mut files = Vec::with_capacity(batch.len());
+            let mut metadata = Vec::with_capacity(batch.len());
+            for file in &batch {
+                let data = tokio::fs::read(&file.path).await?;
+                metadata.push(file.metadata(&data));
+                files.push((file.identifier.as_str(), data));
+            }
+            results.push(self.store_quilt(files, Some(metadata), options).await?);
+        }
+        Ok(results)
+    }
+
+    /// Downloads every file of a Quilt into a directory.
+    ///
+    /// The patches of the Quilt are listed with [`WalrusClient::list_quilt_patches`], and each file is written to
+    /// the path given by its identifier relative to `dir`, creating subdirectories as needed and overwriting
+    /// existing files. Files are downloaded concurrently, a few at a time.
+    ///
+    /// Every identifier is checked before anything is written, so that a Quilt cannot write outside of `dir`.
+    /// Files are checked against the size and hash in their tags, as set by
+    /// [`WalrusClient::store_quilt_from_dir`], and get the modification time in their tags.
+    /// If downloading a file fails, the files written so far are not deleted.
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The ID of the Quilt.
+    /// - `dir`: The directory to write the files to.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PathBuf>)`: The paths of the written files, in the order listed by the Aggregator.
+    /// - `Err(WalrusError::InvalidParameter)`: If an identifier is not a safe relative path.
+    /// - `Err(WalrusError::QuiltPatchMismatch)`: If a file does not match the size or hash in its tags.
+    /// - `Err(WalrusError)`: If listing or reading the Quilt, or writing a file failed.
+    pub async fn download_quilt_to_dir(
+        &self,
+        quilt_id: &BlobId,
+        dir: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>, WalrusError> {
+        let dir = dir.as_ref();
+        let patches = self.list_quilt_patches(quilt_id).await?;
+        let paths = patches
+            .iter()
+            .map(|patch| Ok(dir.join(identifier_path(&patch.identifier)?)))
+            .collect::<Result<Vec<_>, WalrusError>>()?;
+
+        futures_util::stream::iter(patches.iter().zip(&paths))
+            .map(|(patch, path)| async move {
+                let data = self
+                    .read_quilt_blob_by_patch_id(&patch.quilt_patch_id)
+                    .await?;
+                verify_patch(patch, &data)?;
+                if let Some(parent) = path.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+                let mut file = tokio::fs::File::create(path).await?;
+                file.write_all(&data).await?;
+                file.flush().await?;
+                if let Some(modified) = modified_time(patch) {
+                    file.into_std().await.set_modified(modified)?;
+                }
+                Ok::<_, WalrusError>(())
+            })
+            .buffer_unordered(QUILT_DOWNLOAD_CONCURRENCY)
+            .try_collect::<()>()
+            .await?;
+        Ok(paths)
+    }
+
     /// Reads Quilt Blob data by Quilt Patch ID from the Walrus Aggregator service.
     ///
     /// # Arguments
@@ -247,25 +871,66 @@ impl WalrusClient {
     /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
     pub async fn read_quilt_blob_by_patch_id(
         &self,
-        quilt_patch_id: &str,
+        quilt_patch_id: &QuiltPatchId,
     ) -> Result<Vec<u8>, WalrusError> {
-        let url = self
-            .aggregator_url()
-            .join(&format!("v1/blobs/by-quilt-patch-id/{quilt_patch_id}"))
-            .map_err(|e| WalrusError::InvalidUrl(format!("Failed to build URL: {e}")))?;
-
+        let path = format!("v1/blobs/by-quilt-patch-id/{quilt_patch_id}");
         let response = self
-            .http_client()
-            .get(url)
-            .send()
-            .await?
-            .error_for_status()?;
+            .send(
+                RequestKind::ReadQuiltPatch,
+                Idempotency::Idempotent,
+                &path,
+                &[],
+                |url| Some(self.http_client().get(url)),
+            )
+            .await?;
 
-        let bytes = response.bytes().await.map_err(|e| {
-            WalrusError::ParseError(format!("Failed to read quilt blob bytes: {e}"))
-        })?;
+        self.response_stream(RequestKind::ReadQuiltPatch, response)
+            .collect_bytes()
+            .await
+    }
+
+    /// Streams Quilt Blob data by Quilt Patch ID from the Walrus Aggregator service.
+    ///
+    /// # Arguments
+    /// - `quilt_patch_id`: The unique identifier of the Quilt Patch.
+    ///
+    /// # Returns
+    /// - `Ok(BlobStream)`: The stream of Quilt Blob data.
+    /// - `Err(WalrusError)`: If the request failed, possibly due to invalid URL or network error.
+    pub async fn read_quilt_blob_stream_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+    ) -> Result<BlobStream, WalrusError> {
+        self.get_stream(
+            RequestKind::ReadQuiltPatch,
+            &format!("v1/blobs/by-quilt-patch-id/{quilt_patch_id}"),
+        )
+        .await
+    }
 
-        Ok(bytes.to_vec())
+    /// Reads a byte range of a Quilt Blob by Quilt patch ID from the Walrus Aggregator service.
+    ///
+    /// See [`WalrusClient::read_blob_range`] for how the range is read.
+    ///
+    /// # Arguments
+    /// - `quilt_patch_id`: The unique identifier of the Quilt patch.
+    /// - `range`: The bytes to read, e.g. `0..1024` or [`ByteRange::suffix`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes; fewer than requested if the range extends beyond the end of the data.
+    /// - `Err(WalrusError::InvalidParameter)`: If the range is empty.
+    /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
+    pub async fn read_quilt_blob_range_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+        range: impl Into<ByteRange>,
+    ) -> Result<Vec<u8>, WalrusError> {
+        self.get_range(
+            RequestKind::ReadQuiltPatch,
+            &format!("v1/blobs/by-quilt-patch-id/{quilt_patch_id}"),
+            range.into(),
+        )
+        .await
     }
 
     /// Reads Quilt Blob data by Quilt ID and identifier from the Walrus Aggregator service.
@@ -279,26 +944,242 @@ impl WalrusClient {
     /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
     pub async fn read_quilt_blob_by_quilt_id_and_identifier(
         &self,
-        quilt_id: &str,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<Vec<u8>, WalrusError> {
+        let path = format!(
+            "v1/blobs/by-quilt-id/{quilt_id}/{}",
+            utf8_percent_encode(identifier, PATH_SEGMENT)
+        );
+        let response = self
+            .send(
+                RequestKind::ReadQuiltPatch,
+                Idempotency::Idempotent,
+                &path,
+                &[],
+                |url| Some(self.http_client().get(url)),
+            )
+            .await?;
+
+        self.response_stream(RequestKind::ReadQuiltPatch, response)
+            .collect_bytes()
+            .await
+    }
+
+    /// Streams Quilt Blob data by Quilt ID and identifier from the Walrus Aggregator service.
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The unique identifier of the Quilt.
+    /// - `identifier`: The identifier of the Blob within the Quilt.
+    ///
+    /// # Returns
+    /// - `Ok(BlobStream)`: The stream of Quilt Blob data.
+    /// - `Err(WalrusError)`: If the request failed, possibly due to invalid URL or network error.
+    pub async fn read_quilt_blob_stream_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<BlobStream, WalrusError> {
+        self.get_stream(
+            RequestKind::ReadQuiltPatch,
+            &format!(
+                "v1/blobs/by-quilt-id/{quilt_id}/{}",
+                utf8_percent_encode(identifier, PATH_SEGMENT)
+            ),
+        )
+        .await
+    }
+
+    /// Reads a byte range of a Quilt Blob by Quilt ID and identifier from the Walrus Aggregator service.
+    ///
+    /// See [`WalrusClient::read_blob_range`] for how the range is read.
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The unique identifier of the Quilt.
+    /// - `identifier`: The identifier of the Blob within the Quilt.
+    /// - `range`: The bytes to read, e.g. `0..1024` or [`ByteRange::suffix`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes; fewer than requested if the range extends beyond the end of the data.
+    /// - `Err(WalrusError::InvalidParameter)`: If the range is empty.
+    /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
+    pub async fn read_quilt_blob_range_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
         identifier: &str,
+        range: impl Into<ByteRange>,
     ) -> Result<Vec<u8>, WalrusError> {
-        let url = self
-            .aggregator_url()
-            .join(&format!("v1/blobs/by-quilt-id/{quilt_id}/{identifier}"))
-            .map_err(|e| WalrusError::InvalidUrl(format!("Failed to build URL: {e}")))?;
+        self.get_range(
+            RequestKind::ReadQuiltPatch,
+            &format!(
+                "v1/blobs/by-quilt-id/{quilt_id}/{}",
+                utf8_percent_encode(identifier, PATH_SEGMENT)
+            ),
+            range.into(),
+        )
+        .await
+    }
+
+    /// Reads a file of a Quilt by Quilt Patch ID from the Walrus Aggregator service, together with its identifier,
+    /// tags and the other response headers.
+    ///
+    /// # Arguments
+    /// - `quilt_patch_id`: The unique identifier of the Quilt Patch.
+    ///
+    /// # Returns
+    /// - `Ok(QuiltPatchResponse)`: The data and attributes of the file.
+    /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
+    pub async fn read_quilt_patch_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+    ) -> Result<QuiltPatchResponse, WalrusError> {
+        self.read_quilt_patch(&format!("v1/blobs/by-quilt-patch-id/{quilt_patch_id}"))
+            .await
+    }
 
+    /// Reads a file of a Quilt by Quilt ID and identifier from the Walrus Aggregator service, together with its
+    /// tags and the other response headers.
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The unique identifier of the Quilt.
+    /// - `identifier`: The identifier of the Blob within the Quilt.
+    ///
+    /// # Returns
+    /// - `Ok(QuiltPatchResponse)`: The data and attributes of the file.
+    /// - `Err(WalrusError)`: If reading failed, possibly due to invalid URL, network error, or data parsing failure.
+    pub async fn read_quilt_patch_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<QuiltPatchResponse, WalrusError> {
+        self.read_quilt_patch(&format!(
+            "v1/blobs/by-quilt-id/{quilt_id}/{}",
+            utf8_percent_encode(identifier, PATH_SEGMENT)
+        ))
+        .await
+    }
+
+    /// Lists the patches of a Quilt by Quilt ID from the Walrus Aggregator service.
+    ///
+    /// This discovers the contents of a Quilt after the [`QuiltStoreResponse`] of storing it is gone.
+    ///
+    /// # Arguments
+    /// - `quilt_id`: The unique identifier of the Quilt.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<QuiltPatchItem>)`: The identifiers, Quilt Patch IDs and tags of the patches.
+    /// - `Err(WalrusError)`: If listing failed, possibly due to invalid URL, network error, or response parsing failure.
+    pub async fn list_quilt_patches(
+        &self,
+        quilt_id: &BlobId,
+    ) -> Result<Vec<QuiltPatchItem>, WalrusError> {
+        let path = format!("v1/quilts/{quilt_id}/patches");
         let response = self
-            .http_client()
-            .get(url)
-            .send()
-            .await?
-            .error_for_status()?;
+            .send(
+                RequestKind::ListQuiltPatches,
+                Idempotency::Idempotent,
+                &path,
+                &[],
+                |url| Some(self.http_client().get(url)),
+            )
+            .await?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| WalrusError::ParseError(format!("Failed to parse Quilt patches: {e}")))
+    }
 
-        let bytes = response.bytes().await.map_err(|e| {
-            WalrusError::ParseError(format!("Failed to read quilt blob bytes: {e}"))
+    /// Queries the status of the network from the endpoint set with [`WalrusClientBuilder::network_info_path`].
+    ///
+    /// The returned [`NetworkInfo`] reports the current epoch and how long epochs last, which converts end epochs
+    /// of stored Blobs to expiry times, as well as the limits and storage prices of the network.
+    ///
+    /// Walrus Aggregators and Publishers do not serve this information, and no public deployment offers such an
+    /// endpoint, so this method only works against a service you run yourself: the endpoint is requested from
+    /// the Aggregator endpoints, and must return the JSON printed by `walrus info --json`. Without such a
+    /// service, deserialize that output into a [`NetworkInfo`] directly.
+    ///
+    /// # Returns
+    /// - `Ok(NetworkInfo)`: The epochs and limits of the network.
+    /// - `Err(WalrusError::InvalidParameter)`: If no network info path is configured.
+    /// - `Err(WalrusError)`: If the request failed, possibly due to invalid URL, network error, or response parsing failure.
+    pub async fn network_info(&self) -> Result<NetworkInfo, WalrusError> {
+        let path = self.network_info_path.as_deref().ok_or_else(|| {
+            WalrusError::InvalidParameter(
+                "no network info path configured; see WalrusClientBuilder::network_info_path"
+                    .to_string(),
+            )
         })?;
+        let response = self
+            .send(
+                RequestKind::Status,
+                Idempotency::Idempotent,
+                path,
+                &[],
+                |url| Some(self.http_client().get(url)),
+            )
+            .await?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| WalrusError::ParseError(format!("Failed to parse NetworkInfo: {e}")))
+    }
+
+    /// Estimates the cost of storing a Blob at the current prices of the network, before storing it.
+    ///
+    /// The encoded length of the Blob is computed for the number of shards reported by the network. To quote many
+    /// Blobs, query the [`NetworkInfo`] once and use [`NetworkInfo::estimate_store_cost`] instead.
+    ///
+    /// Like [`WalrusClient::network_info`], this needs a network info service you run yourself.
+    ///
+    /// # Arguments
+    /// - `size`: The unencoded size of the Blob in bytes.
+    /// - `epochs`: The number of epochs to store the Blob for.
+    ///
+    /// # Returns
+    /// - `Ok(StoreCostEstimate)`: The encoded length of the Blob and the cost of storing it.
+    /// - `Err(WalrusError::InvalidParameter)`: If the size or number of epochs exceeds the limits of the network.
+    /// - `Err(WalrusError)`: If querying the network failed, or it does not report its prices.
+    pub async fn estimate_store_cost(
+        &self,
+        size: u64,
+        epochs: u64,
+    ) -> Result<StoreCostEstimate, WalrusError> {
+        self.network_info().await?.estimate_store_cost(size, epochs)
+    }
+
+    /// Retrieves the OpenAPI specification of the Walrus Aggregator service.
+    ///
+    /// # Returns
+    /// - `Ok(serde_json::Value)`: The OpenAPI document.
+    /// - `Err(WalrusError)`: If the request failed, possibly due to invalid URL, network error, or response parsing failure.
+    pub async fn api_spec(&self) -> Result<serde_json::Value, WalrusError> {
+        self.fetch_api_spec(RequestKind::Status).await
+    }
 
-        Ok(bytes.to_vec())
+    /// Retrieves the OpenAPI specification of the Walrus Publisher service.
+    ///
+    /// # Returns
+    /// - `Ok(serde_json::Value)`: The OpenAPI document.
+    /// - `Err(WalrusError)`: If the request failed, possibly due to invalid URL, network error, or response parsing failure.
+    pub async fn publisher_api_spec(&self) -> Result<serde_json::Value, WalrusError> {
+        self.fetch_api_spec(RequestKind::PublisherStatus).await
+    }
+
+    /// Retrieves the OpenAPI specification from the endpoints `kind` is sent to.
+    async fn fetch_api_spec(&self, kind: RequestKind) -> Result<serde_json::Value, WalrusError> {
+        let response = self
+            .send(kind, Idempotency::Idempotent, "v1/api", &[], |url| {
+                Some(self.http_client().get(url))
+            })
+            .await?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| WalrusError::ParseError(format!("Failed to parse API specification: {e}")))
     }
 
     /// Retrieves metadata for a Blob by its Blob ID from the Walrus Aggregator service.
@@ -309,18 +1190,17 @@ impl WalrusClient {
     /// # Returns
     /// - `Ok(BlobMetadata)`: Successfully retrieved the Blob metadata.
     /// - `Err(WalrusError)`: If retrieval failed, possibly due to invalid URL, network error, or response header parsing failure.
-    pub async fn get_blob_metadata(&self, blob_id: &str) -> Result<BlobMetadata, WalrusError> {
-        let url = self
-            .aggregator_url()
-            .join(&format!("v1/blobs/{blob_id}"))
-            .map_err(|e| WalrusError::InvalidUrl(format!("Failed to build URL: {e}")))?;
-
+    pub async fn get_blob_metadata(&self, blob_id: &BlobId) -> Result<BlobMetadata, WalrusError> {
+        let path = format!("v1/blobs/{blob_id}");
         let response = self
-            .http_client()
-            .head(url)
-            .send()
-            .await?
-            .error_for_status()?;
+            .send(
+                RequestKind::BlobMetadata,
+                Idempotency::Idempotent,
+                &path,
+                &[],
+                |url| Some(self.http_client().head(url)),
+            )
+            .await?;
 
         /// Helper function to extract a header value from HTTP response headers.
         ///
@@ -355,4 +1235,186 @@ impl WalrusClient {
             etag,
         })
     }
+
+    /// Checks the data read for a Blob against its Blob ID if reads are verified.
+    ///
+    /// The Blob ID is recomputed on a blocking thread, as encoding is CPU-intensive.
+    async fn verified(&self, blob_id: &BlobId, data: Vec<u8>) -> Result<Vec<u8>, WalrusError> {
+        if !self.verify_reads {
+            return Ok(data);
+        }
+        let encoding_config = self.encoding_config;
+        let blob_id = *blob_id;
+        tokio::task::spawn_blocking(move || {
+            encoding_config
+                .verify_blob_id(&blob_id, &data)
+                .map(|()| data)
+        })
+        .await
+        .map_err(|e| WalrusError::Other(format!("Blob verification failed: {e}")))?
+    }
+
+    /// Creates a tracker reporting the progress of a transfer to the observer, if one is set.
+    fn progress_tracker(&self, kind: RequestKind, total: Option<u64>) -> Option<ProgressTracker> {
+        self.progress_observer
+            .as_ref()
+            .map(|observer| ProgressTracker::new(observer.clone(), kind, total))
+    }
+
+    /// Streams the body of a response, reporting the download progress to the observer.
+    fn response_stream(&self, kind: RequestKind, response: Response) -> BlobStream {
+        let stream = BlobStream::from_response(response);
+        match self.progress_tracker(kind, stream.content_length()) {
+            Some(tracker) => {
+                let content_length = stream.content_length();
+                tracker.restart();
+                BlobStream::new(tracker.observe(stream), content_length)
+            }
+            None => stream,
+        }
+    }
+
+    /// Reads a Quilt patch from the given path of the Aggregator service, keeping the response headers.
+    async fn read_quilt_patch(&self, path: &str) -> Result<QuiltPatchResponse, WalrusError> {
+        let response = self
+            .send(
+                RequestKind::ReadQuiltPatch,
+                Idempotency::Idempotent,
+                path,
+                &[],
+                |url| Some(self.http_client().get(url)),
+            )
+            .await?;
+
+        let headers = response.headers().clone();
+        let data = self
+            .response_stream(RequestKind::ReadQuiltPatch, response)
+            .collect_bytes()
+            .await?;
+        Ok(QuiltPatchResponse::from_parts(
+            data,
+            headers,
+            &self.quilt_tag_headers,
+        ))
+    }
+
+    /// Sends a GET request for the given path to the Aggregator service and streams the response body.
+    ///
+    /// Only establishing the response is retried; errors while streaming the body are returned to the caller.
+    async fn get_stream(&self, kind: RequestKind, path: &str) -> Result<BlobStream, WalrusError> {
+        let response = self
+            .send(kind, Idempotency::Idempotent, path, &[], |url| {
+                Some(self.http_client().get(url))
+            })
+            .await?;
+
+        Ok(self.response_stream(kind, response))
+    }
+
+    /// Sends a GET request with a `Range` header for the given path to the Aggregator service and reads the
+    /// selected bytes, from either a partial response or a response containing the whole data.
+    async fn get_range(
+        &self,
+        kind: RequestKind,
+        path: &str,
+        range: ByteRange,
+    ) -> Result<Vec<u8>, WalrusError> {
+        range.validate()?;
+        let response = self
+            .send(kind, Idempotency::Idempotent, path, &[], |url| {
+                Some(self.http_client().get(url).header(RANGE, range.to_string()))
+            })
+            .await?;
+
+        let partial = (response.status() == StatusCode::PARTIAL_CONTENT).then(|| {
+            response
+                .headers()
+                .get(CONTENT_RANGE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        });
+        let stream = self.response_stream(kind, response);
+        if let Some(content_range) = partial {
+            range.read_partial(stream, content_range.as_deref()).await
+        } else {
+            range.slice(stream).await
+        }
+    }
+}
+
+/// Indicates if an error is a `404 Not Found` response.
+fn is_not_found(error: &WalrusError) -> bool {
+    matches!(error, WalrusError::ApiError(error) if error.is_not_found())
+}
+
+/// The number of chunks of a chunked Blob fetched concurrently while reading it.
+const CHUNK_READ_AHEAD: usize = 4;
+
+/// The number of files of a Quilt downloaded concurrently by [`WalrusClient::download_quilt_to_dir`].
+const QUILT_DOWNLOAD_CONCURRENCY: usize = 4;
+
+/// Returns the Blob ID of a stored Blob.
+fn stored_blob_id(result: &BlobStoreResult) -> Result<BlobId, WalrusError> {
+    match result {
+        BlobStoreResult::NewlyCreated(created) => Ok(created.blob_object.blob_id),
+        BlobStoreResult::AlreadyCertified(certified) => Ok(certified.blob_id),
+        BlobStoreResult::MarkedInvalid(invalid) => Err(WalrusError::Other(format!(
+            "Blob {} was marked as invalid",
+            invalid.blob_id
+        ))),
+        BlobStoreResult::Error(failure) => Err(WalrusError::Other(format!(
+            "Storing the blob failed in phase {}: {}",
+            failure.failure_phase, failure.error_msg
+        ))),
+        BlobStoreResult::Unknown(_) => Err(WalrusError::ParseError(
+            "Store result contains neither a new nor a certified blob".to_string(),
+        )),
+    }
+}
+
+/// The characters percent-encoded in a URL path segment: everything except unreserved characters.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Builds the URL of an API path relative to the base URL of an endpoint.
+fn endpoint_url(base: &Url, path: &str, query: &[(&str, String)]) -> Result<Url, WalrusError> {
+    let mut url = base
+        .join(path)
+        .map_err(|e| WalrusError::InvalidUrl(format!("Failed to build URL: {e}")))?;
+    if !query.is_empty() {
+        url.query_pairs_mut().extend_pairs(query);
+    }
+    Ok(url)
+}
+
+/// Creates an upload body from a stream, reporting its progress to the tracker if there is one.
+///
+/// The tracker is restarted, as a new body is created for every attempt of a request.
+fn observed_body(
+    tracker: Option<&ProgressTracker>,
+    stream: impl Stream<Item = Result<Bytes, io::Error>> + Send + 'static,
+) -> Body {
+    match tracker {
+        Some(tracker) => {
+            tracker.restart();
+            Body::wrap_stream(tracker.observe(stream))
+        }
+        None => Body::wrap_stream(stream),
+    }
+}
+
+/// Returns a request factory for [`WalrusClient::send`] that retargets `request` to each URL
+/// as long as it can be cloned.
+fn cloning(client: &Client, request: Request) -> impl FnMut(Url) -> Option<RequestBuilder> {
+    let client = client.clone();
+    let mut pending = Some(request);
+    move |url| {
+        let mut current = pending.take()?;
+        pending = current.try_clone();
+        *current.url_mut() = url;
+        Some(RequestBuilder::from_parts(client.clone(), current))
+    }
 }
@@ -0,0 +1,302 @@
+use blake2::Blake2b;
+use blake2::digest::Digest;
+use blake2::digest::consts::U32;
+use reed_solomon_simd::ReedSolomonEncoder;
+
+use crate::error::WalrusError;
+use crate::ids::BlobId;
+
+type Blake2b256 = Blake2b<U32>;
+
+/// A node of a Merkle tree, i.e. a 32-byte Blake2b hash.
+type Node = [u8; 32];
+
+/// The node used to pad levels of a Merkle tree with an odd number of nodes.
+const EMPTY_NODE: Node = [0; 32];
+
+/// The domain separation prefix of leaf hashes in a Merkle tree.
+const LEAF_PREFIX: u8 = 0;
+
+/// The domain separation prefix of inner node hashes in a Merkle tree.
+const INNER_PREFIX: u8 = 1;
+
+/// The identifier of the Reed-Solomon (`RS2`) encoding type, which is part of the input of the Blob ID.
+const RS2_ENCODING: u8 = 1;
+
+/// The Reed-Solomon encoder works on symbols consisting of 16-bit field elements.
+const SYMBOL_ALIGNMENT: u64 = 2;
+
+/// `EncodingConfig` describes how Walrus encodes Blobs for a storage committee with a given number of shards.
+///
+/// It implements the parts of Walrus' two-dimensional Reed-Solomon (`RS2`) encoding that are needed to
+/// compute the [`BlobId`] of some data locally, without uploading it to a Publisher: the data is arranged in a
+/// matrix of source symbols, which is expanded in both dimensions. Every row and column of the expanded matrix
+/// (the primary and secondary slivers) is committed to with a Merkle root, and the Blob ID is derived from the
+/// Merkle root over these sliver hashes together with the encoding type and the length of the data.
+///
+/// The Blob ID depends on the number of shards of the network the Blob is stored on; both Walrus Mainnet and
+/// Testnet use [`EncodingConfig::DEFAULT_N_SHARDS`] shards. The legacy RaptorQ encoding is not supported, as
+/// Publishers only store Blobs encoded with `RS2`.
+///
+/// # Example
+/// ```
+/// use walrus_rs::EncodingConfig;
+///
+/// let config = EncodingConfig::new(10)?;
+/// let blob_id = config.compute_blob_id(b"Hello, Walrus!")?;
+/// assert_eq!(blob_id, config.compute_blob_id(b"Hello, Walrus!")?);
+/// # Ok::<(), walrus_rs::WalrusError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodingConfig {
+    n_shards: u16,
+    source_symbols_primary: u16,
+    source_symbols_secondary: u16,
+}
+
+impl EncodingConfig {
+    /// The number of shards of Walrus Mainnet and Testnet.
+    pub const DEFAULT_N_SHARDS: u16 = 1000;
+
+    /// The smallest number of shards that tolerates a faulty shard.
+    const MIN_N_SHARDS: u16 = 4;
+
+    /// Creates the `EncodingConfig` for a network with the given number of shards.
+    ///
+    /// # Arguments
+    /// - `n_shards`: The number of shards of the storage committee.
+    ///
+    /// # Returns
+    /// - `Ok(EncodingConfig)`: The encoding configuration.
+    /// - `Err(WalrusError::InvalidParameter)`: If there are fewer than 4 shards.
+    pub fn new(n_shards: u16) -> Result<Self, WalrusError> {
+        if n_shards < Self::MIN_N_SHARDS {
+            return Err(WalrusError::InvalidParameter(format!(
+                "Number of shards must be at least {}, got {n_shards}",
+                Self::MIN_N_SHARDS
+            )));
+        }
+        let max_faulty = (n_shards - 1) / 3;
+        Ok(Self {
+            n_shards,
+            source_symbols_primary: n_shards - 2 * max_faulty,
+            source_symbols_secondary: n_shards - max_faulty,
+        })
+    }
+
+    /// Returns the number of shards.
+    pub fn n_shards(&self) -> u16 {
+        self.n_shards
+    }
+
+    /// Returns the number of source symbols of the primary encoding, i.e. the number of rows of the data matrix.
+    pub fn source_symbols_primary(&self) -> u16 {
+        self.source_symbols_primary
+    }
+
+    /// Returns the number of source symbols of the secondary encoding, i.e. the number of columns of the data matrix.
+    pub fn source_symbols_secondary(&self) -> u16 {
+        self.source_symbols_secondary
+    }
+
+    /// Computes the size of the symbols a Blob of the given length is split into.
+    ///
+    /// # Returns
+    /// - `Ok(u16)`: The symbol size in bytes.
+    /// - `Err(WalrusError::InvalidParameter)`: If the Blob is too large to be encoded.
+    pub fn symbol_size(&self, unencoded_length: u64) -> Result<u16, WalrusError> {
+        let source_symbols =
+            u64::from(self.source_symbols_primary) * u64::from(self.source_symbols_secondary);
+        unencoded_length
+            .max(1)
+            .div_ceil(source_symbols)
+            .next_multiple_of(SYMBOL_ALIGNMENT)
+            .try_into()
+            .map_err(|_| {
+                WalrusError::InvalidParameter(format!(
+                    "Blob of {unencoded_length} bytes is too large to be encoded with {} shards",
+                    self.n_shards
+                ))
+            })
+    }
+
+    /// Computes the length of a Blob of the given length once encoded, which is the size storage is paid for.
+    ///
+    /// This includes the slivers of all shards, as well as the metadata stored by every shard: the Blob ID and
+    /// the hashes of the primary and secondary sliver of every shard.
+    ///
+    /// # Returns
+    /// - `Ok(u64)`: The encoded length in bytes.
+    /// - `Err(WalrusError::InvalidParameter)`: If the Blob is too large to be encoded.
+    pub fn encoded_blob_length(&self, unencoded_length: u64) -> Result<u64, WalrusError> {
+        let n_shards = u64::from(self.n_shards);
+        let symbol_size = u64::from(self.symbol_size(unencoded_length)?);
+        let slivers = n_shards
+            * (u64::from(self.source_symbols_primary) + u64::from(self.source_symbols_secondary))
+            * symbol_size;
+        // Blob IDs and sliver hashes are both 32-byte hashes.
+        let hash_length = EMPTY_NODE.len() as u64;
+        let metadata = n_shards * (2 * n_shards * hash_length + hash_length);
+        Ok(slivers + metadata)
+    }
+
+    /// Computes the Blob ID of the given data, as assigned by Walrus when the data is stored.
+    ///
+    /// Encoding is CPU-intensive and needs memory of about three times the size of the data;
+    /// in asynchronous code, consider running it with `tokio::task::spawn_blocking`.
+    ///
+    /// # Arguments
+    /// - `data`: The unencoded Blob data.
+    ///
+    /// # Returns
+    /// - `Ok(BlobId)`: The Blob ID of the data.
+    /// - `Err(WalrusError::InvalidParameter)`: If the data is too large to be encoded.
+    pub fn compute_blob_id(&self, data: &[u8]) -> Result<BlobId, WalrusError> {
+        let unencoded_length = data.len() as u64;
+        let symbol_size = usize::from(self.symbol_size(unencoded_length)?);
+        let n_shards = usize::from(self.n_shards);
+        let n_rows = usize::from(self.source_symbols_primary);
+        let n_columns = usize::from(self.source_symbols_secondary);
+        let row_bytes = n_columns * symbol_size;
+
+        // The data matrix, expanded to all rows: the first `n_rows` rows hold the zero-padded data,
+        // the remaining rows the recovery symbols of the primary encoding of each column.
+        let mut matrix = vec![0u8; n_shards * row_bytes];
+        matrix[..data.len()].copy_from_slice(data);
+        let symbol = |row: usize, column: usize| (row * n_columns + column) * symbol_size;
+
+        let mut encoder = ReedSolomonEncoder::new(n_rows, n_shards - n_rows, symbol_size)
+            .map_err(encoding_error)?;
+        for column in 0..n_columns {
+            for row in 0..n_rows {
+                let start = symbol(row, column);
+                encoder
+                    .add_original_shard(&matrix[start..start + symbol_size])
+                    .map_err(encoding_error)?;
+            }
+            let recovery = encoder.encode().map_err(encoding_error)?;
+            for (row, shard) in (n_rows..n_shards).zip(recovery.recovery_iter()) {
+                let start = symbol(row, column);
+                matrix[start..start + symbol_size].copy_from_slice(shard);
+            }
+        }
+
+        // Expanding each row with the secondary encoding completes the primary sliver. The leaf hashes of its
+        // symbols are kept to compute the Merkle roots of the secondary slivers, i.e. the columns.
+        let mut leaves = vec![EMPTY_NODE; n_shards * n_shards];
+        let mut primary_hashes = Vec::with_capacity(n_shards);
+        encoder
+            .reset(n_columns, n_shards - n_columns, symbol_size)
+            .map_err(encoding_error)?;
+        for (row, row_leaves) in leaves.chunks_mut(n_shards).enumerate() {
+            let row_data = &matrix[row * row_bytes..(row + 1) * row_bytes];
+            for (leaf, original) in row_leaves.iter_mut().zip(row_data.chunks(symbol_size)) {
+                *leaf = leaf_hash(&[original]);
+                encoder
+                    .add_original_shard(original)
+                    .map_err(encoding_error)?;
+            }
+            let recovery = encoder.encode().map_err(encoding_error)?;
+            for (leaf, shard) in row_leaves[n_columns..]
+                .iter_mut()
+                .zip(recovery.recovery_iter())
+            {
+                *leaf = leaf_hash(&[shard]);
+            }
+            primary_hashes.push(merkle_root(row_leaves.to_vec()));
+        }
+
+        let secondary_hashes: Vec<Node> = (0..n_shards)
+            .map(|column| {
+                merkle_root(
+                    (0..n_shards)
+                        .map(|row| leaves[row * n_shards + column])
+                        .collect(),
+                )
+            })
+            .collect();
+
+        // Sliver pair `i` consists of primary sliver `i` and secondary sliver `n_shards - 1 - i`.
+        let pair_leaves = primary_hashes
+            .iter()
+            .zip(secondary_hashes.iter().rev())
+            .map(|(primary, secondary)| leaf_hash(&[primary.as_slice(), secondary.as_slice()]))
+            .collect();
+        let root = merkle_root(pair_leaves);
+
+        let blob_id: [u8; 32] = Blake2b256::new()
+            .chain_update([RS2_ENCODING])
+            .chain_update(unencoded_length.to_le_bytes())
+            .chain_update(root)
+            .finalize()
+            .into();
+        Ok(BlobId::new(blob_id))
+    }
+
+    /// Checks that the given data has the expected Blob ID.
+    ///
+    /// # Arguments
+    /// - `blob_id`: The expected Blob ID.
+    /// - `data`: The unencoded Blob data.
+    ///
+    /// # Returns
+    /// - `Ok(())`: The data matches the Blob ID.
+    /// - `Err(WalrusError::BlobIdMismatch)`: If the data has a different Blob ID.
+    /// - `Err(WalrusError::InvalidParameter)`: If the data is too large to be encoded.
+    pub fn verify_blob_id(&self, blob_id: &BlobId, data: &[u8]) -> Result<(), WalrusError> {
+        let actual = self.compute_blob_id(data)?;
+        if actual != *blob_id {
+            return Err(WalrusError::BlobIdMismatch {
+                expected: *blob_id,
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for EncodingConfig {
+    /// Returns the encoding configuration of Walrus Mainnet and Testnet.
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_N_SHARDS).expect("the default number of shards is valid")
+    }
+}
+
+/// Hashes the concatenation of the given parts as a leaf of a Merkle tree.
+fn leaf_hash(parts: &[&[u8]]) -> Node {
+    let mut hasher = Blake2b256::new_with_prefix([LEAF_PREFIX]);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Computes the root of the Merkle tree with the given leaf hashes.
+///
+/// Levels with an odd number of nodes are padded with an empty node.
+fn merkle_root(mut level: Vec<Node>) -> Node {
+    if level.is_empty() {
+        return EMPTY_NODE;
+    }
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(EMPTY_NODE);
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                Blake2b256::new()
+                    .chain_update([INNER_PREFIX])
+                    .chain_update(pair[0])
+                    .chain_update(pair[1])
+                    .finalize()
+                    .into()
+            })
+            .collect();
+    }
+    level[0]
+}
+
+fn encoding_error(error: reed_solomon_simd::Error) -> WalrusError {
+    WalrusError::Other(format!("Failed to encode blob: {error}"))
+}
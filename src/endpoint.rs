@@ -0,0 +1,195 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use reqwest::Url;
+
+/// Determines the order in which the endpoints of an [`EndpointPool`] are tried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionStrategy {
+    /// Spreads requests evenly by starting at the next endpoint for every request.
+    #[default]
+    RoundRobin,
+    /// Prefers endpoints with a lower observed latency, picking the first endpoint at random weighted by inverse latency.
+    LatencyWeighted,
+    /// Always starts with the first healthy endpoint in the configured order.
+    Priority,
+}
+
+/// A snapshot of the health of a single endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointStatus {
+    /// The URL of the endpoint.
+    pub url: Url,
+    /// Indicates if the endpoint is currently used for requests.
+    pub healthy: bool,
+    /// The number of consecutive failed requests.
+    pub consecutive_failures: u32,
+    /// The smoothed latency of successful requests, if any request succeeded yet.
+    pub latency: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct EndpointHealth {
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+    latency: Option<Duration>,
+}
+
+impl EndpointHealth {
+    fn is_healthy(&self, now: Instant) -> bool {
+        self.unhealthy_until.is_none_or(|until| until <= now)
+    }
+}
+
+/// `EndpointPool` holds the URLs of one kind of Walrus service (Aggregators or Publishers) and tracks their health.
+///
+/// An endpoint is marked unhealthy after `failure_threshold` consecutive transient failures and is skipped for the
+/// `cooldown` duration. Afterwards it is tried again, and a single further failure marks it unhealthy again.
+/// Unhealthy endpoints are still tried as a last resort when no healthy endpoint is left.
+#[derive(Debug)]
+pub struct EndpointPool {
+    urls: Vec<Url>,
+    strategy: SelectionStrategy,
+    failure_threshold: u32,
+    cooldown: Duration,
+    next: AtomicUsize,
+    health: Mutex<Vec<EndpointHealth>>,
+}
+
+impl EndpointPool {
+    /// The default number of consecutive failures after which an endpoint is marked unhealthy.
+    pub const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+    /// The default duration an unhealthy endpoint is skipped.
+    pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+    /// Creates a new `EndpointPool`.
+    ///
+    /// # Arguments
+    /// - `urls`: The endpoint URLs, in priority order. Must not be empty.
+    /// - `strategy`: The strategy used to order the endpoints for each request.
+    /// - `failure_threshold`: The number of consecutive failures after which an endpoint is marked unhealthy.
+    /// - `cooldown`: The duration an unhealthy endpoint is skipped.
+    pub(crate) fn new(
+        urls: Vec<Url>,
+        strategy: SelectionStrategy,
+        failure_threshold: u32,
+        cooldown: Duration,
+    ) -> Self {
+        let health = urls.iter().map(|_| EndpointHealth::default()).collect();
+        Self {
+            urls,
+            strategy,
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            next: AtomicUsize::new(0),
+            health: Mutex::new(health),
+        }
+    }
+
+    /// Returns the URLs of all endpoints, in priority order.
+    pub fn urls(&self) -> &[Url] {
+        &self.urls
+    }
+
+    /// Returns the first configured endpoint.
+    pub fn primary(&self) -> &Url {
+        &self.urls[0]
+    }
+
+    /// Returns the strategy used to order the endpoints.
+    pub fn strategy(&self) -> SelectionStrategy {
+        self.strategy
+    }
+
+    /// Returns a snapshot of the health of all endpoints.
+    pub fn status(&self) -> Vec<EndpointStatus> {
+        let now = Instant::now();
+        let health = self.health.lock().unwrap_or_else(|e| e.into_inner());
+        self.urls
+            .iter()
+            .zip(health.iter())
+            .map(|(url, health)| EndpointStatus {
+                url: url.clone(),
+                healthy: health.is_healthy(now),
+                consecutive_failures: health.consecutive_failures,
+                latency: health.latency,
+            })
+            .collect()
+    }
+
+    /// Returns the indices of the endpoints in the order they should be tried for the next request.
+    ///
+    /// Healthy endpoints are ordered according to the selection strategy, followed by unhealthy endpoints
+    /// ordered by the end of their cool-down.
+    pub(crate) fn candidates(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let health = self.health.lock().unwrap_or_else(|e| e.into_inner());
+        let (mut healthy, mut unhealthy): (Vec<usize>, Vec<usize>) =
+            (0..self.urls.len()).partition(|&i| health[i].is_healthy(now));
+
+        match self.strategy {
+            SelectionStrategy::Priority => {}
+            SelectionStrategy::RoundRobin => {
+                if !healthy.is_empty() {
+                    let start = self.next.fetch_add(1, Ordering::Relaxed) % healthy.len();
+                    healthy.rotate_left(start);
+                }
+            }
+            SelectionStrategy::LatencyWeighted => {
+                // Endpoints without measurements are treated as fast so that they get probed.
+                let latency = |i: usize| health[i].latency.unwrap_or_default().as_secs_f64();
+                healthy.sort_by(|&a, &b| latency(a).total_cmp(&latency(b)));
+                if healthy.len() > 1 {
+                    let weights: Vec<f64> = healthy
+                        .iter()
+                        .map(|&i| 1.0 / latency(i).max(0.001))
+                        .collect();
+                    let mut pick = rand::rng().random_range(0.0..weights.iter().sum::<f64>());
+                    let chosen = weights
+                        .iter()
+                        .position(|w| {
+                            pick -= w;
+                            pick < 0.0
+                        })
+                        .unwrap_or(0);
+                    let first = healthy.remove(chosen);
+                    healthy.insert(0, first);
+                }
+            }
+        }
+
+        unhealthy.sort_by_key(|&i| health[i].unhealthy_until);
+        healthy.extend(unhealthy);
+        healthy
+    }
+
+    /// Returns the URL of the endpoint with the given index.
+    pub(crate) fn url(&self, index: usize) -> &Url {
+        &self.urls[index]
+    }
+
+    /// Records a successful request to the endpoint with the given index.
+    pub(crate) fn record_success(&self, index: usize, latency: Duration) {
+        let mut health = self.health.lock().unwrap_or_else(|e| e.into_inner());
+        let endpoint = &mut health[index];
+        endpoint.consecutive_failures = 0;
+        endpoint.unhealthy_until = None;
+        endpoint.latency = Some(match endpoint.latency {
+            // Exponentially weighted moving average giving the new sample a weight of 1/4.
+            Some(previous) => (previous * 3 + latency) / 4,
+            None => latency,
+        });
+    }
+
+    /// Records a transient failure of the endpoint with the given index.
+    pub(crate) fn record_failure(&self, index: usize) {
+        let mut health = self.health.lock().unwrap_or_else(|e| e.into_inner());
+        let endpoint = &mut health[index];
+        endpoint.consecutive_failures = endpoint.consecutive_failures.saturating_add(1);
+        if endpoint.consecutive_failures >= self.failure_threshold {
+            endpoint.unhealthy_until = Some(Instant::now() + self.cooldown);
+        }
+    }
+}
@@ -1,6 +1,12 @@
-use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::fmt;
+
+use reqwest::{Response, StatusCode, Url};
+use serde::Deserialize;
 use thiserror::Error;
 
+use crate::ids::BlobId;
+
 /// The `WalrusError` enum represents all possible errors that can occur within the `walrus_rs` library.
 ///
 /// It encapsulates HTTP request errors, URL parsing errors, API-specific errors, response parsing errors, and other general errors.
@@ -12,12 +18,29 @@ pub enum WalrusError {
     /// An invalid URL was provided.
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
-    /// An error returned by the Walrus API. Contains the HTTP status code and error message.
-    #[error("API error: {0} - {1}")]
-    ApiError(StatusCode, String),
+    /// An error response returned by the Walrus API.
+    #[error("API error: {0}")]
+    ApiError(Box<ApiError>),
     /// Failed to parse the response.
     #[error("Failed to parse response: {0}")]
     ParseError(String),
+    /// The data received for a Blob does not match its Blob ID.
+    #[error("Blob ID mismatch: expected {expected}, but the received data has Blob ID {actual}")]
+    BlobIdMismatch {
+        /// The Blob ID that was requested.
+        expected: BlobId,
+        /// The Blob ID computed from the received data.
+        actual: BlobId,
+    },
+    /// The data of a chunked Blob does not match its manifest.
+    #[error("Chunked blob does not match its manifest: {0}")]
+    ManifestMismatch(String),
+    /// The data of a Quilt patch does not match the size or hash in its tags.
+    #[error("Quilt patch does not match its tags: {0}")]
+    QuiltPatchMismatch(String),
+    /// Reading or writing local data failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
     /// An invalid parameter was provided.
     #[error("Invalid parameter: {0}")]
     InvalidParameter(String),
@@ -28,3 +51,242 @@ pub enum WalrusError {
     #[error("Other error: {0}")]
     Other(String),
 }
+
+impl WalrusError {
+    /// Returns the error response of the Walrus API, if this is an [`WalrusError::ApiError`].
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            WalrusError::ApiError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ApiError> for WalrusError {
+    fn from(error: ApiError) -> Self {
+        WalrusError::ApiError(Box::new(error))
+    }
+}
+
+/// The kind of request sent to a Walrus service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RequestKind {
+    /// Storing a Blob on a Publisher.
+    StoreBlob,
+    /// Storing a Quilt on a Publisher.
+    StoreQuilt,
+    /// Reading a Blob from an Aggregator.
+    ReadBlob,
+    /// Reading a Quilt patch from an Aggregator.
+    ReadQuiltPatch,
+    /// Reading the metadata of a Blob from an Aggregator.
+    BlobMetadata,
+    /// Listing the patches of a Quilt on an Aggregator.
+    ListQuiltPatches,
+    /// Querying the status or API specification of an Aggregator.
+    Status,
+    /// Querying the API specification of a Publisher.
+    PublisherStatus,
+}
+
+impl fmt::Display for RequestKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RequestKind::StoreBlob => "store blob",
+            RequestKind::StoreQuilt => "store quilt",
+            RequestKind::ReadBlob => "read blob",
+            RequestKind::ReadQuiltPatch => "read quilt patch",
+            RequestKind::BlobMetadata => "blob metadata",
+            RequestKind::ListQuiltPatches => "list quilt patches",
+            RequestKind::Status => "status",
+            RequestKind::PublisherStatus => "publisher status",
+        })
+    }
+}
+
+/// `ApiError` describes an error response (a non-2xx status) of a Walrus Aggregator or Publisher.
+///
+/// Walrus services describe errors with a JSON body of the form
+/// `{"error": {"code": 404, "status": "NOT_FOUND", "message": "...", "details": [{"reason": "...", ...}]}}`,
+/// which is decoded into [`ApiErrorBody`] when present. Other bodies, e.g. from proxies, are kept as the message.
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    status: StatusCode,
+    kind: RequestKind,
+    url: Url,
+    message: String,
+    body: Option<ApiErrorBody>,
+}
+
+/// The error object in the body of a Walrus error response.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ApiErrorBody {
+    /// The HTTP status code.
+    pub code: Option<u16>,
+    /// The canonical error code, e.g. `NOT_FOUND`.
+    pub status: Option<String>,
+    /// The human-readable error message.
+    #[serde(default)]
+    pub message: String,
+    /// Further details about the error.
+    #[serde(default)]
+    pub details: Vec<ApiErrorDetail>,
+}
+
+/// A detail of a Walrus error response.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ApiErrorDetail {
+    /// The type of the detail, e.g. `ErrorInfo`.
+    #[serde(rename = "@type")]
+    pub detail_type: Option<String>,
+    /// The machine-readable reason of the error, e.g. `BLOB_NOT_FOUND`.
+    pub reason: Option<String>,
+    /// The domain of the service that produced the error.
+    pub domain: Option<String>,
+    /// Additional structured information about the error.
+    #[serde(default)]
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+/// The envelope of a Walrus error response.
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ApiErrorBody,
+}
+
+/// Phrases in error reasons and messages that indicate that the wallet of a Publisher cannot pay for a store,
+/// matched against the lowercased text. Other shortages, such as of storage nodes, do not match.
+const INSUFFICIENT_FUNDS_PHRASES: &[&str] = &[
+    "insufficient balance",
+    "insufficient funds",
+    "insufficientcoinbalance",
+    "sufficient balance",
+    "not enough wal",
+    "no compatible payment coin",
+    "no compatible gas coin",
+];
+
+impl ApiError {
+    /// Creates an `ApiError` from an error response, consuming its body.
+    pub(crate) async fn from_response(kind: RequestKind, response: Response) -> Self {
+        let status = response.status();
+        let url = response.url().clone();
+        let text = response.text().await.unwrap_or_default();
+        Self::new(status, kind, url, &text)
+    }
+
+    /// Creates an `ApiError` from the status and body of an error response.
+    ///
+    /// # Arguments
+    /// - `status`: The HTTP status code of the response.
+    /// - `kind`: The kind of the failed request.
+    /// - `url`: The URL the request was sent to.
+    /// - `body`: The response body, decoded if it is a Walrus error object.
+    pub fn new(status: StatusCode, kind: RequestKind, url: Url, body: &str) -> Self {
+        let body = serde_json::from_str::<ErrorResponse>(body)
+            .map(|response| response.error)
+            .map_err(|_| body.trim().to_string());
+        let (message, body) = match body {
+            Ok(body) => (body.message.clone(), Some(body)),
+            Err(text) => (text, None),
+        };
+        Self {
+            status,
+            kind,
+            url,
+            message,
+            body,
+        }
+    }
+
+    /// Returns the HTTP status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the kind of the failed request.
+    pub fn kind(&self) -> RequestKind {
+        self.kind
+    }
+
+    /// Returns the URL of the failed request.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the error message, or the raw response body if it is not a Walrus error object.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the decoded Walrus error object, if the response contained one.
+    pub fn body(&self) -> Option<&ApiErrorBody> {
+        self.body.as_ref()
+    }
+
+    /// Returns the canonical error code, e.g. `NOT_FOUND`.
+    pub fn code(&self) -> Option<&str> {
+        self.body.as_ref()?.status.as_deref()
+    }
+
+    /// Returns the reason of the first error detail that carries one, e.g. `BLOB_NOT_FOUND`.
+    pub fn reason(&self) -> Option<&str> {
+        self.details().iter().find_map(|d| d.reason.as_deref())
+    }
+
+    /// Returns the details of the error.
+    pub fn details(&self) -> &[ApiErrorDetail] {
+        self.body.as_ref().map_or(&[], |body| &body.details)
+    }
+
+    /// Indicates if the requested Blob, Quilt or patch does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.status == StatusCode::NOT_FOUND
+    }
+
+    /// Indicates if the error is transient and the request may succeed when repeated,
+    /// matching the statuses retried by [`RetryPolicy::default`](crate::retry::RetryPolicy::default).
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.status,
+            StatusCode::REQUEST_TIMEOUT
+                | StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// Indicates if a store failed because the Publisher's wallet lacks the WAL or SUI to pay for it.
+    ///
+    /// Walrus has no dedicated status for this, so the reason and message are matched against known phrases.
+    pub fn is_insufficient_funds(&self) -> bool {
+        self.reason()
+            .into_iter()
+            .chain([self.message.as_str()])
+            .map(str::to_lowercase)
+            .any(|text| {
+                INSUFFICIENT_FUNDS_PHRASES
+                    .iter()
+                    .any(|phrase| text.contains(phrase))
+            })
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} request to {} failed with {}",
+            self.kind, self.url, self.status
+        )?;
+        if let Some(reason) = self.reason() {
+            write!(f, " ({reason})")?;
+        }
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        Ok(())
+    }
+}
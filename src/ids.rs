@@ -0,0 +1,263 @@
+use std::fmt;
+use std::str::FromStr;
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::WalrusError;
+
+/// Implements `Serialize` and `Deserialize` through the `Display` and `FromStr` representation of a type.
+macro_rules! impl_serde_via_str {
+    ($type:ty) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// The identifier of a Blob, derived from its content and encoding.
+///
+/// It is represented as the URL-safe base64 encoding (without padding) of 32 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BlobId([u8; 32]);
+
+impl BlobId {
+    /// The length of a Blob ID in bytes.
+    pub const LENGTH: usize = 32;
+
+    /// Creates a Blob ID from its raw bytes.
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw bytes of the Blob ID.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for BlobId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&URL_SAFE_NO_PAD.encode(self.0))
+    }
+}
+
+impl FromStr for BlobId {
+    type Err = WalrusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(s)
+            .map_err(|e| WalrusError::InvalidParameter(format!("Invalid blob ID {s:?}: {e}")))?;
+        Self::try_from(bytes.as_slice()).map_err(|_| {
+            WalrusError::InvalidParameter(format!("Invalid blob ID {s:?}: expected 32 bytes"))
+        })
+    }
+}
+
+impl From<[u8; 32]> for BlobId {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<BlobId> for [u8; 32] {
+    fn from(id: BlobId) -> Self {
+        id.0
+    }
+}
+
+impl TryFrom<&[u8]> for BlobId {
+    type Error = WalrusError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = bytes.try_into().map_err(|_| {
+            WalrusError::InvalidParameter(format!(
+                "Invalid blob ID: expected 32 bytes, got {}",
+                bytes.len()
+            ))
+        })?;
+        Ok(Self(bytes))
+    }
+}
+
+impl_serde_via_str!(BlobId);
+
+/// Parses a `0x`-prefixed hex string of up to 32 bytes, left-padding short forms such as `0x2` with zeros.
+fn parse_hex_32(s: &str, kind: &str) -> Result<[u8; 32], WalrusError> {
+    let invalid =
+        |reason: &str| WalrusError::InvalidParameter(format!("Invalid {kind} {s:?}: {reason}"));
+    let hex = s
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid("missing 0x prefix"))?;
+    if hex.is_empty() || hex.len() > 64 {
+        return Err(invalid("expected 1 to 64 hex digits"));
+    }
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid("invalid hex digit"));
+    }
+
+    let mut bytes = [0u8; 32];
+    let padded = format!("{hex:0>64}");
+    for (byte, pair) in bytes.iter_mut().zip(padded.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|_| invalid("invalid hex digit"))?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid("invalid hex digit"))?;
+    }
+    Ok(bytes)
+}
+
+/// Formats 32 bytes as a `0x`-prefixed lowercase hex string.
+fn format_hex_32(bytes: &[u8; 32], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("0x")?;
+    bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+}
+
+/// Defines a 32-byte identifier represented as a `0x`-prefixed hex string.
+macro_rules! hex_id {
+    ($(#[$meta:meta])* $name:ident, $kind:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name([u8; 32]);
+
+        impl $name {
+            /// The length of the identifier in bytes.
+            pub const LENGTH: usize = 32;
+
+            /// Creates the identifier from its raw bytes.
+            pub const fn new(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the raw bytes of the identifier.
+            pub fn as_bytes(&self) -> &[u8; 32] {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                format_hex_32(&self.0, f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = WalrusError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_hex_32(s, $kind).map(Self)
+            }
+        }
+
+        impl From<[u8; 32]> for $name {
+            fn from(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; 32] {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl_serde_via_str!($name);
+    };
+}
+
+hex_id!(
+    /// The identifier of a Sui object, such as a Blob object, represented as a `0x`-prefixed hex string.
+    ObjectId,
+    "object ID"
+);
+
+hex_id!(
+    /// A Sui account address, represented as a `0x`-prefixed hex string.
+    SuiAddress,
+    "Sui address"
+);
+
+/// The identifier of a patch within a Quilt.
+///
+/// It consists of the Blob ID of the Quilt followed by version-specific bytes locating the patch within the Quilt,
+/// represented as the URL-safe base64 encoding (without padding) of the concatenation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct QuiltPatchId {
+    quilt_id: BlobId,
+    patch_bytes: Vec<u8>,
+}
+
+impl QuiltPatchId {
+    /// The maximum number of bytes locating the patch within the Quilt.
+    const MAX_PATCH_BYTES: usize = 32;
+
+    /// Creates a Quilt Patch ID from the Quilt's Blob ID and the bytes locating the patch.
+    ///
+    /// # Returns
+    /// - `Ok(QuiltPatchId)`: The Quilt Patch ID.
+    /// - `Err(WalrusError::InvalidParameter)`: If the patch bytes are empty or too long.
+    pub fn new(quilt_id: BlobId, patch_bytes: Vec<u8>) -> Result<Self, WalrusError> {
+        if patch_bytes.is_empty() || patch_bytes.len() > Self::MAX_PATCH_BYTES {
+            return Err(WalrusError::InvalidParameter(format!(
+                "Invalid quilt patch ID: expected 1 to {} patch bytes, got {}",
+                Self::MAX_PATCH_BYTES,
+                patch_bytes.len()
+            )));
+        }
+        Ok(Self {
+            quilt_id,
+            patch_bytes,
+        })
+    }
+
+    /// Returns the Blob ID of the Quilt containing the patch.
+    pub fn quilt_id(&self) -> &BlobId {
+        &self.quilt_id
+    }
+
+    /// Returns the version-specific bytes locating the patch within the Quilt.
+    pub fn patch_bytes(&self) -> &[u8] {
+        &self.patch_bytes
+    }
+
+    /// Returns the raw bytes of the Quilt Patch ID.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.quilt_id.as_bytes().to_vec();
+        bytes.extend_from_slice(&self.patch_bytes);
+        bytes
+    }
+}
+
+impl fmt::Display for QuiltPatchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&URL_SAFE_NO_PAD.encode(self.to_bytes()))
+    }
+}
+
+impl FromStr for QuiltPatchId {
+    type Err = WalrusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = URL_SAFE_NO_PAD.decode(s).map_err(|e| {
+            WalrusError::InvalidParameter(format!("Invalid quilt patch ID {s:?}: {e}"))
+        })?;
+        if bytes.len() <= BlobId::LENGTH {
+            return Err(WalrusError::InvalidParameter(format!(
+                "Invalid quilt patch ID {s:?}: too short"
+            )));
+        }
+        let (quilt_id, patch_bytes) = bytes.split_at(BlobId::LENGTH);
+        Self::new(BlobId::try_from(quilt_id)?, patch_bytes.to_vec())
+    }
+}
+
+impl_serde_via_str!(QuiltPatchId);
@@ -10,23 +10,95 @@
 //! Module overview:
 //! - [`client`]: Provides the asynchronous Walrus client [`WalrusClient`].
 //! - [`blocking_client`]: Provides the blocking Walrus client [`BlockingWalrusClient`].
+//! - [`builder`]: Provides [`WalrusClientBuilder`] for configuring the HTTP transport of a client.
+//! - [`endpoint`]: Provides the [`EndpointPool`] used to fail over between Aggregator and Publisher endpoints.
+//! - [`options`]: Defines the [`StoreOptions`] used when storing Blobs and Quilts.
+//! - [`retry`]: Defines the [`RetryPolicy`] applied to failed requests.
+//! - [`store`]: Defines the [`WalrusStore`] trait with in-memory and filesystem implementations.
+//! - [`stream`]: Provides [`BlobStream`] for reading Blobs without buffering them in memory.
+//! - [`chunked`]: Provides chunked uploads of large files described by a [`ChunkManifest`].
+//! - [`quilt_dir`]: Provides storing directories as Quilts, controlled by [`QuiltDirOptions`], and downloading
+//!   them back.
+//! - [`progress`]: Defines the [`ProgressObserver`] notified of the progress of uploads and downloads.
+//! - [`range`]: Defines the [`ByteRange`] used to read parts of Blobs.
+//! - [`ids`]: Defines strongly typed identifiers such as [`BlobId`] and [`ObjectId`].
+//! - [`network`]: Defines the [`NetworkInfo`] describing the epochs, limits and prices of a Walrus network.
+//! - [`encoding`]: Provides [`EncodingConfig`] for computing the [`BlobId`] of data locally.
 //! - [`models`]: Defines the data structures used by the Walrus API.
-//! - [`error`]: Defines the library's error types [`WalrusError`].
+//! - [`error`]: Defines the library's error types [`WalrusError`] and [`ApiError`].
+//! - `testing`: Provides `MockWalrusServer`, an in-process mock of the Walrus API for offline tests
+//!   (requires the `testing` feature).
 //!
 //! [`client`]: crate::client
 //! [`blocking_client`]: crate::blocking_client
+//! [`builder`]: crate::builder
+//! [`endpoint`]: crate::endpoint
+//! [`EndpointPool`]: crate::endpoint::EndpointPool
+//! [`options`]: crate::options
+//! [`StoreOptions`]: crate::options::StoreOptions
+//! [`retry`]: crate::retry
+//! [`RetryPolicy`]: crate::retry::RetryPolicy
+//! [`store`]: crate::store
+//! [`WalrusStore`]: crate::store::WalrusStore
+//! [`stream`]: crate::stream
+//! [`BlobStream`]: crate::stream::BlobStream
+//! [`chunked`]: crate::chunked
+//! [`ChunkManifest`]: crate::chunked::ChunkManifest
+//! [`quilt_dir`]: crate::quilt_dir
+//! [`QuiltDirOptions`]: crate::quilt_dir::QuiltDirOptions
+//! [`progress`]: crate::progress
+//! [`ProgressObserver`]: crate::progress::ProgressObserver
+//! [`range`]: crate::range
+//! [`ByteRange`]: crate::range::ByteRange
+//! [`ids`]: crate::ids
+//! [`BlobId`]: crate::ids::BlobId
+//! [`ObjectId`]: crate::ids::ObjectId
+//! [`network`]: crate::network
+//! [`NetworkInfo`]: crate::network::NetworkInfo
+//! [`encoding`]: crate::encoding
+//! [`EncodingConfig`]: crate::encoding::EncodingConfig
 //! [`models`]: crate::models
 //! [`error`]: crate::error
 //! [`WalrusClient`]: crate::client::WalrusClient
 //! [`BlockingWalrusClient`]: crate::blocking_client::BlockingWalrusClient
+//! [`WalrusClientBuilder`]: crate::builder::WalrusClientBuilder
 //! [`WalrusError`]: crate::error::WalrusError
+//! [`ApiError`]: crate::error::ApiError
 
+pub mod builder;
+pub mod chunked;
 pub mod client;
+pub mod encoding;
+pub mod endpoint;
 pub mod error;
+pub mod ids;
 pub mod models;
+pub mod network;
+pub mod options;
+pub mod progress;
+pub mod quilt_dir;
+pub mod range;
+pub mod retry;
+pub mod store;
+pub mod stream;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub mod blocking_client;
 
-pub use blocking_client::BlockingWalrusClient;
+pub use blocking_client::{BlobReader, BlockingWalrusClient, BlockingWalrusClientBuilder};
+pub use builder::WalrusClientBuilder;
+pub use chunked::{ChunkManifest, ChunkedStoreResult, ChunkedUploadOptions, ManifestChunk};
 pub use client::WalrusClient;
-pub use error::WalrusError;
+pub use encoding::EncodingConfig;
+pub use endpoint::{EndpointPool, SelectionStrategy};
+pub use error::{ApiError, RequestKind, WalrusError};
+pub use ids::{BlobId, ObjectId, QuiltPatchId, SuiAddress};
+pub use network::{NetworkInfo, StoragePrices, StoreCostEstimate};
+pub use options::StoreOptions;
+pub use progress::{Progress, ProgressObserver, TransferDirection};
+pub use quilt_dir::QuiltDirOptions;
+pub use range::ByteRange;
+pub use retry::RetryPolicy;
+pub use store::{FileSystemStore, MemoryStore, WalrusStore};
+pub use stream::BlobStream;
@@ -1,16 +1,19 @@
+use reqwest::header::{CONTENT_TYPE, HeaderMap};
 use serde::{Deserialize, Serialize};
+
+use crate::ids::{BlobId, ObjectId, QuiltPatchId};
 use std::collections::HashMap;
 
 /// Represents a Blob object in the Walrus API.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlobObject {
-    /// The unique ID of the Blob.
-    pub id: String,
+    /// The ID of the Sui object representing the Blob.
+    pub id: ObjectId,
     /// The epoch at which the Blob was registered.
     pub registered_epoch: u64,
     /// The ID of the Blob.
-    pub blob_id: String,
+    pub blob_id: BlobId,
     /// The size of the Blob.
     pub size: u64,
     /// The encoding type of the Blob.
@@ -27,8 +30,8 @@ pub struct BlobObject {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StorageInfo {
-    /// The storage ID.
-    pub id: String,
+    /// The ID of the Sui storage resource object.
+    pub id: ObjectId,
     /// The starting epoch of the storage.
     pub start_epoch: u64,
     /// The ending epoch of the storage.
@@ -65,6 +68,9 @@ pub struct NewlyCreated {
     pub resource_operation: ResourceOperation,
     /// The cost.
     pub cost: u64,
+    /// The ID of the shared Blob object wrapping the Blob, if it was stored as a shared Blob.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_blob_object: Option<ObjectId>,
 }
 
 /// Represents an event in the Walrus API.
@@ -82,21 +88,128 @@ pub struct Event {
 #[serde(rename_all = "camelCase")]
 pub struct AlreadyCertified {
     /// The ID of the Blob.
-    pub blob_id: String,
-    /// The associated event.
-    pub event: Event,
+    pub blob_id: BlobId,
+    /// The event that certified the Blob, if reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<Event>,
+    /// The ID of a Blob object certifying the Blob, reported instead of the event when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object: Option<ObjectId>,
     /// The ending epoch.
     pub end_epoch: u64,
 }
 
-/// Represents the result of a Blob storage operation.
+/// Represents a Blob that was marked as invalid, e.g. because it was incorrectly encoded.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkedInvalid {
+    /// The ID of the Blob.
+    pub blob_id: BlobId,
+    /// The event that marked the Blob as invalid.
+    pub event: Event,
+}
+
+/// Represents a failure reported by the Publisher while storing a Blob.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BlobStoreResult {
-    /// Information about a newly created Blob (if applicable).
-    pub newly_created: Option<NewlyCreated>,
-    /// Information about an already certified Blob (if applicable).
-    pub already_certified: Option<AlreadyCertified>,
+pub struct StoreFailure {
+    /// The ID of the Blob, if it was computed before the failure.
+    pub blob_id: Option<BlobId>,
+    /// The phase of the store operation that failed.
+    pub failure_phase: String,
+    /// The error message.
+    pub error_msg: String,
+}
+
+/// Represents the result of a Blob storage operation, as returned by the Publisher.
+///
+/// Results of a kind unknown to this version of the library are kept as [`BlobStoreResult::Unknown`] instead of
+/// failing deserialization.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlobStoreResult {
+    /// The Blob was newly created, or an existing registration of it was reused or extended.
+    NewlyCreated(NewlyCreated),
+    /// The Blob was already certified for at least the requested epochs.
+    AlreadyCertified(AlreadyCertified),
+    /// The Blob was marked as invalid.
+    MarkedInvalid(MarkedInvalid),
+    /// The Publisher failed to store the Blob.
+    Error(StoreFailure),
+    /// A result of an unknown kind, as the raw JSON returned by the Publisher.
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for BlobStoreResult {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        fn variant<T: serde::de::DeserializeOwned, E: serde::de::Error>(
+            value: serde_json::Value,
+        ) -> Result<T, E> {
+            serde_json::from_value(value).map_err(E::custom)
+        }
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let serde_json::Value::Object(object) = &value else {
+            return Ok(Self::Unknown(value));
+        };
+        let mut entries = object.iter();
+        let (Some((kind, inner)), None) = (entries.next(), entries.next()) else {
+            return Ok(Self::Unknown(value));
+        };
+        let inner = inner.clone();
+        Ok(match kind.as_str() {
+            "newlyCreated" => Self::NewlyCreated(variant(inner)?),
+            "alreadyCertified" => Self::AlreadyCertified(variant(inner)?),
+            "markedInvalid" => Self::MarkedInvalid(variant(inner)?),
+            "error" => Self::Error(variant(inner)?),
+            _ => Self::Unknown(value),
+        })
+    }
+}
+
+impl BlobStoreResult {
+    /// Returns the ID of the Blob, if the result reports it.
+    pub fn blob_id(&self) -> Option<&BlobId> {
+        match self {
+            Self::NewlyCreated(created) => Some(&created.blob_object.blob_id),
+            Self::AlreadyCertified(certified) => Some(&certified.blob_id),
+            Self::MarkedInvalid(invalid) => Some(&invalid.blob_id),
+            Self::Error(failure) => failure.blob_id.as_ref(),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Returns the epoch at which the storage of the Blob ends, if the Blob is stored.
+    pub fn end_epoch(&self) -> Option<u64> {
+        match self {
+            Self::NewlyCreated(created) => Some(created.blob_object.storage.end_epoch),
+            Self::AlreadyCertified(certified) => Some(certified.end_epoch),
+            _ => None,
+        }
+    }
+
+    /// Returns the ID of the Sui object representing the Blob, if the result reports it.
+    pub fn object_id(&self) -> Option<&ObjectId> {
+        match self {
+            Self::NewlyCreated(created) => Some(&created.blob_object.id),
+            Self::AlreadyCertified(certified) => certified.object.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the cost of storing the Blob, which is only reported for newly created Blobs.
+    pub fn cost(&self) -> Option<u64> {
+        match self {
+            Self::NewlyCreated(created) => Some(created.cost),
+            _ => None,
+        }
+    }
+
+    /// Indicates if the Blob is stored, i.e. if it was newly created or is already certified.
+    pub fn is_stored(&self) -> bool {
+        matches!(self, Self::NewlyCreated(_) | Self::AlreadyCertified(_))
+    }
 }
 
 /// Represents a stored Quilt Blob.
@@ -106,7 +219,7 @@ pub struct StoredQuiltBlob {
     /// The identifier.
     pub identifier: String,
     /// The Quilt Patch ID.
-    pub quilt_patch_id: String,
+    pub quilt_patch_id: QuiltPatchId,
 }
 
 /// Represents the response from a Quilt storage operation.
@@ -119,6 +232,22 @@ pub struct QuiltStoreResponse {
     pub stored_quilt_blobs: Vec<StoredQuiltBlob>,
 }
 
+/// Describes a patch of a Quilt, as listed by the Aggregator.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QuiltPatchItem {
+    /// The identifier of the patch within the Quilt.
+    pub identifier: String,
+    /// The Quilt Patch ID, which reads the patch without knowing its identifier.
+    #[serde(rename = "patchId", alias = "patch_id")]
+    pub quilt_patch_id: QuiltPatchId,
+    /// The tags the patch was stored with.
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+    /// The size of the patch in bytes, if reported by the Aggregator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
 /// Represents metadata for a Quilt.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QuiltMetadata {
@@ -138,3 +267,66 @@ pub struct BlobMetadata {
     /// The ETag.
     pub etag: String,
 }
+
+/// A file read from a Quilt, together with the attributes the Aggregator returns in the response headers.
+#[derive(Debug, Clone)]
+pub struct QuiltPatchResponse {
+    /// The contents of the file.
+    pub data: Vec<u8>,
+    /// The identifier of the file within the Quilt, if reported by the Aggregator.
+    pub identifier: Option<String>,
+    /// The content type of the file, if reported by the Aggregator.
+    ///
+    /// A `content-type` tag set when storing the file is returned here, as the Aggregator serves it as the
+    /// `Content-Type` of the response.
+    pub content_type: Option<String>,
+    /// The tags of the file, which the Aggregator returns as response headers.
+    ///
+    /// The headers cannot be told apart from those added by the Aggregator or proxies, such as `Date` or
+    /// `X-Request-Id`, so only the headers configured with
+    /// [`WalrusClientBuilder::quilt_tag_headers`](crate::builder::WalrusClientBuilder::quilt_tag_headers) are
+    /// reported as tags; all others are only available in [`QuiltPatchResponse::headers`].
+    pub tags: HashMap<String, String>,
+    /// All headers of the response.
+    pub headers: HeaderMap,
+}
+
+impl QuiltPatchResponse {
+    /// The response header carrying the identifier of the file.
+    pub const IDENTIFIER_HEADER: &'static str = "x-quilt-patch-identifier";
+
+    /// The response headers reported as tags by default, the tags Walrus Aggregators return unless configured
+    /// otherwise. `content-type` is reported as [`QuiltPatchResponse::content_type`] instead.
+    pub const DEFAULT_TAG_HEADERS: &'static [&'static str] = &[
+        "authorization",
+        "content-disposition",
+        "content-encoding",
+        "content-language",
+        "content-location",
+        "link",
+    ];
+
+    /// Creates a response from the data and headers of a Quilt patch read, reporting the given headers as tags.
+    pub(crate) fn from_parts(data: Vec<u8>, headers: HeaderMap, tag_headers: &[String]) -> Self {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        };
+        let identifier = header(Self::IDENTIFIER_HEADER);
+        let content_type = header(CONTENT_TYPE.as_str());
+        let tags = headers
+            .iter()
+            .filter(|(name, _)| tag_headers.iter().any(|tag| tag == name.as_str()))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+            .collect();
+        Self {
+            data,
+            identifier,
+            content_type,
+            tags,
+            headers,
+        }
+    }
+}
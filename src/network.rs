@@ -0,0 +1,370 @@
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::encoding::EncodingConfig;
+use crate::error::WalrusError;
+
+/// `NetworkInfo` describes the epochs and limits of a Walrus network, as reported by
+/// [`WalrusClient::network_info`](crate::client::WalrusClient::network_info).
+///
+/// It converts the epochs in which Blobs expire, such as
+/// [`StorageInfo::end_epoch`](crate::models::StorageInfo::end_epoch), to wall-clock times.
+///
+/// Walrus Aggregators and Publishers do not report it, and no public deployment serves it, so the client reads
+/// it from a service you run yourself at the endpoint configured with
+/// [`WalrusClientBuilder::network_info_path`](crate::builder::WalrusClientBuilder::network_info_path). It can
+/// also be deserialized from the output of `walrus info --json` directly.
+///
+/// On the wire, it has the layout printed by `walrus info --json`:
+/// `{"epochInfo": {"currentEpoch": 1, "startOfCurrentEpoch": {"DateTime": "2025-01-01T00:00:00Z"},
+/// "epochDuration": {"secs": 86400, "nanos": 0}, "maxEpochsAhead": 53}, "storageInfo": {"nShards": 1000},
+/// "sizeInfo": {"storageUnitSize": 1048576, "maxBlobSize": 13958643712},
+/// "priceInfo": {"storagePricePerUnitSize": 11000, "writePricePerUnitSize": 20000}}`, where `priceInfo` is
+/// optional. Other fields are ignored.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use walrus_rs::NetworkInfo;
+///
+/// let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_750_000_000);
+/// let info = NetworkInfo {
+///     current_epoch: 10,
+///     current_epoch_start: Some(start),
+///     epoch_duration: Duration::from_secs(14 * 24 * 60 * 60),
+///     max_epochs_ahead: 53,
+///     max_blob_size: 13_958_643_712,
+///     n_shards: 1000,
+///     prices: None,
+/// };
+/// assert_eq!(info.expiry_time(12), Some(start + 2 * info.epoch_duration));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "InfoOutput", into = "InfoOutput")]
+pub struct NetworkInfo {
+    /// The current epoch.
+    pub current_epoch: u64,
+    /// The time at which the current epoch started, if known.
+    pub current_epoch_start: Option<SystemTime>,
+    /// The duration of an epoch.
+    pub epoch_duration: Duration,
+    /// The maximum number of epochs ahead that Blobs can be stored for.
+    pub max_epochs_ahead: u64,
+    /// The maximum size of a Blob in bytes.
+    pub max_blob_size: u64,
+    /// The number of shards of the storage committee.
+    pub n_shards: u16,
+    /// The current prices of storage, if reported.
+    pub prices: Option<StoragePrices>,
+}
+
+impl NetworkInfo {
+    /// Returns the time at which the given epoch starts, or started.
+    ///
+    /// Later epochs are assumed to last [`NetworkInfo::epoch_duration`], like the current one.
+    ///
+    /// # Returns
+    /// - `Some(SystemTime)`: The start of the epoch.
+    /// - `None`: If the start of the current epoch is unknown, or the time cannot be represented.
+    pub fn epoch_start(&self, epoch: u64) -> Option<SystemTime> {
+        let start = self.current_epoch_start?;
+        if epoch >= self.current_epoch {
+            let ahead = u32::try_from(epoch - self.current_epoch).ok()?;
+            start.checked_add(self.epoch_duration.checked_mul(ahead)?)
+        } else {
+            let behind = u32::try_from(self.current_epoch - epoch).ok()?;
+            start.checked_sub(self.epoch_duration.checked_mul(behind)?)
+        }
+    }
+
+    /// Returns the time at which a Blob stored until the given end epoch expires.
+    ///
+    /// Blobs are stored up to, but not including, their end epoch, so they expire when it starts.
+    ///
+    /// # Arguments
+    /// - `end_epoch`: The end epoch of the storage, e.g. [`StorageInfo::end_epoch`](crate::models::StorageInfo::end_epoch).
+    ///
+    /// # Returns
+    /// - `Some(SystemTime)`: The expiry time.
+    /// - `None`: If the start of the current epoch is unknown, or the time cannot be represented.
+    pub fn expiry_time(&self, end_epoch: u64) -> Option<SystemTime> {
+        self.epoch_start(end_epoch)
+    }
+
+    /// Returns the number of epochs a Blob stored now must be stored for to be kept for at least the given duration.
+    ///
+    /// The result is capped at [`NetworkInfo::max_epochs_ahead`].
+    pub fn epochs_for(&self, duration: Duration) -> u64 {
+        let remaining = self
+            .epoch_start(self.current_epoch.saturating_add(1))
+            .and_then(|next| next.duration_since(SystemTime::now()).ok())
+            .unwrap_or(self.epoch_duration);
+        let Some(beyond) = duration.checked_sub(remaining) else {
+            return 1.min(self.max_epochs_ahead);
+        };
+        let epoch_nanos = self.epoch_duration.as_nanos().max(1);
+        let more = beyond.as_nanos().div_ceil(epoch_nanos);
+        u64::try_from(more + 1)
+            .unwrap_or(u64::MAX)
+            .min(self.max_epochs_ahead)
+    }
+
+    /// Estimates the cost of storing a Blob at the current prices, before storing it.
+    ///
+    /// # Arguments
+    /// - `size`: The unencoded size of the Blob in bytes.
+    /// - `epochs`: The number of epochs to store the Blob for.
+    ///
+    /// # Returns
+    /// - `Ok(StoreCostEstimate)`: The encoded length of the Blob and the cost of storing it.
+    /// - `Err(WalrusError::InvalidParameter)`: If the Blob is larger than [`NetworkInfo::max_blob_size`], or the
+    ///   number of epochs is zero or exceeds [`NetworkInfo::max_epochs_ahead`].
+    /// - `Err(WalrusError::Other)`: If the network does not report its prices.
+    pub fn estimate_store_cost(
+        &self,
+        size: u64,
+        epochs: u64,
+    ) -> Result<StoreCostEstimate, WalrusError> {
+        if size > self.max_blob_size {
+            return Err(WalrusError::InvalidParameter(format!(
+                "Blob of {size} bytes exceeds the max blob size of {} bytes",
+                self.max_blob_size
+            )));
+        }
+        if epochs == 0 || epochs > self.max_epochs_ahead {
+            return Err(WalrusError::InvalidParameter(format!(
+                "epochs must be between 1 and {}, got {epochs}",
+                self.max_epochs_ahead
+            )));
+        }
+        let prices = self.prices.as_ref().ok_or_else(|| {
+            WalrusError::Other("the network does not report storage prices".to_string())
+        })?;
+        prices.estimate(self.encoding_config()?.encoded_blob_length(size)?, epochs)
+    }
+
+    /// Returns the [`EncodingConfig`] of the network, e.g. to compute Blob IDs locally.
+    ///
+    /// # Returns
+    /// - `Ok(EncodingConfig)`: The encoding configuration.
+    /// - `Err(WalrusError::InvalidParameter)`: If the network has fewer than 4 shards.
+    pub fn encoding_config(&self) -> Result<EncodingConfig, WalrusError> {
+        EncodingConfig::new(self.n_shards)
+    }
+}
+
+/// `StoragePrices` are the prices of storage on a Walrus network, in FROST (10^-9 WAL).
+///
+/// Storage is paid per started storage unit of the encoded length of a Blob: once for writing it, and for every
+/// epoch it is stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoragePrices {
+    /// The size of a storage unit in bytes.
+    pub storage_unit_size: u64,
+    /// The price of storing one unit for one epoch.
+    pub storage_price_per_unit_size: u64,
+    /// The price of writing one unit, paid once.
+    pub write_price_per_unit_size: u64,
+}
+
+impl StoragePrices {
+    /// The size of a storage unit on Walrus Mainnet and Testnet, 1 MiB.
+    pub const DEFAULT_STORAGE_UNIT_SIZE: u64 = 1024 * 1024;
+
+    /// Computes the cost of storing a Blob of the given encoded length for the given number of epochs.
+    ///
+    /// # Arguments
+    /// - `encoded_length`: The encoded length of the Blob, as computed by [`EncodingConfig::encoded_blob_length`].
+    /// - `epochs`: The number of epochs to store the Blob for.
+    ///
+    /// # Returns
+    /// - `Ok(StoreCostEstimate)`: The cost of storing the Blob.
+    /// - `Err(WalrusError::InvalidParameter)`: If the storage unit size is zero, or the cost overflows.
+    pub fn estimate(
+        &self,
+        encoded_length: u64,
+        epochs: u64,
+    ) -> Result<StoreCostEstimate, WalrusError> {
+        if self.storage_unit_size == 0 {
+            return Err(WalrusError::InvalidParameter(
+                "storage unit size must be greater than zero".to_string(),
+            ));
+        }
+        let storage_units = encoded_length.div_ceil(self.storage_unit_size);
+        let overflow = || {
+            WalrusError::InvalidParameter(format!(
+                "cost of storing {encoded_length} bytes for {epochs} epochs overflows"
+            ))
+        };
+        let storage_cost = storage_units
+            .checked_mul(self.storage_price_per_unit_size)
+            .and_then(|cost| cost.checked_mul(epochs))
+            .ok_or_else(overflow)?;
+        let write_cost = storage_units
+            .checked_mul(self.write_price_per_unit_size)
+            .ok_or_else(overflow)?;
+        let estimate = StoreCostEstimate {
+            encoded_length,
+            storage_units,
+            epochs,
+            storage_cost,
+            write_cost,
+        };
+        estimate.total_cost()?;
+        Ok(estimate)
+    }
+}
+
+/// The estimated cost of storing a Blob, in FROST (10^-9 WAL), as computed by
+/// [`NetworkInfo::estimate_store_cost`].
+///
+/// Storing a Blob that is already certified for long enough costs nothing, and reusing existing storage resources
+/// can cost less, so this is an upper bound of what [`WalrusClient::store_blob`](crate::client::WalrusClient::store_blob)
+/// reports as the cost. Gas fees are not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreCostEstimate {
+    /// The encoded length of the Blob in bytes.
+    pub encoded_length: u64,
+    /// The number of storage units paid for.
+    pub storage_units: u64,
+    /// The number of epochs the Blob is stored for.
+    pub epochs: u64,
+    /// The cost of storing the Blob for the epochs.
+    pub storage_cost: u64,
+    /// The cost of writing the Blob.
+    pub write_cost: u64,
+}
+
+impl StoreCostEstimate {
+    /// Returns the total cost, the sum of the storage and write costs.
+    ///
+    /// # Returns
+    /// - `Ok(u64)`: The total cost.
+    /// - `Err(WalrusError::InvalidParameter)`: If the sum overflows, as in [`StoragePrices::estimate`].
+    pub fn total_cost(&self) -> Result<u64, WalrusError> {
+        self.storage_cost
+            .checked_add(self.write_cost)
+            .ok_or_else(|| {
+                WalrusError::InvalidParameter(format!(
+                    "cost of storing {} bytes for {} epochs overflows",
+                    self.encoded_length, self.epochs
+                ))
+            })
+    }
+}
+
+/// The layout of `walrus info --json`, of which `NetworkInfo` keeps the fields it needs.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InfoOutput {
+    epoch_info: EpochInfo,
+    storage_info: StorageSummary,
+    size_info: SizeInfo,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    price_info: Option<PriceInfo>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EpochInfo {
+    current_epoch: u64,
+    start_of_current_epoch: EpochStart,
+    epoch_duration: Duration,
+    max_epochs_ahead: u64,
+}
+
+/// The start of the current epoch, or a message explaining why it is unknown.
+#[derive(Serialize, Deserialize)]
+enum EpochStart {
+    DateTime(String),
+    Message(String),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StorageSummary {
+    n_shards: u16,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SizeInfo {
+    #[serde(default = "default_storage_unit_size")]
+    storage_unit_size: u64,
+    max_blob_size: u64,
+}
+
+fn default_storage_unit_size() -> u64 {
+    StoragePrices::DEFAULT_STORAGE_UNIT_SIZE
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PriceInfo {
+    storage_price_per_unit_size: u64,
+    write_price_per_unit_size: u64,
+}
+
+impl TryFrom<InfoOutput> for NetworkInfo {
+    type Error = WalrusError;
+
+    fn try_from(output: InfoOutput) -> Result<Self, Self::Error> {
+        let current_epoch_start = match output.epoch_info.start_of_current_epoch {
+            EpochStart::DateTime(time) => {
+                Some(humantime::parse_rfc3339_weak(&time).map_err(|e| {
+                    WalrusError::ParseError(format!("Invalid start of epoch {time:?}: {e}"))
+                })?)
+            }
+            EpochStart::Message(_) => None,
+        };
+        Ok(Self {
+            current_epoch: output.epoch_info.current_epoch,
+            current_epoch_start,
+            epoch_duration: output.epoch_info.epoch_duration,
+            max_epochs_ahead: output.epoch_info.max_epochs_ahead,
+            max_blob_size: output.size_info.max_blob_size,
+            n_shards: output.storage_info.n_shards,
+            prices: output.price_info.map(|price_info| StoragePrices {
+                storage_unit_size: output.size_info.storage_unit_size,
+                storage_price_per_unit_size: price_info.storage_price_per_unit_size,
+                write_price_per_unit_size: price_info.write_price_per_unit_size,
+            }),
+        })
+    }
+}
+
+impl From<NetworkInfo> for InfoOutput {
+    fn from(info: NetworkInfo) -> Self {
+        let start_of_current_epoch = match info.current_epoch_start {
+            Some(start) => EpochStart::DateTime(humantime::format_rfc3339(start).to_string()),
+            None => EpochStart::Message("unknown".to_string()),
+        };
+        Self {
+            epoch_info: EpochInfo {
+                current_epoch: info.current_epoch,
+                start_of_current_epoch,
+                epoch_duration: info.epoch_duration,
+                max_epochs_ahead: info.max_epochs_ahead,
+            },
+            storage_info: StorageSummary {
+                n_shards: info.n_shards,
+            },
+            size_info: SizeInfo {
+                storage_unit_size: info
+                    .prices
+                    .as_ref()
+                    .map_or(StoragePrices::DEFAULT_STORAGE_UNIT_SIZE, |prices| {
+                        prices.storage_unit_size
+                    }),
+                max_blob_size: info.max_blob_size,
+            },
+            price_info: info.prices.map(|prices| PriceInfo {
+                storage_price_per_unit_size: prices.storage_price_per_unit_size,
+                write_price_per_unit_size: prices.write_price_per_unit_size,
+            }),
+        }
+    }
+}
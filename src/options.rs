@@ -0,0 +1,109 @@
+use crate::error::WalrusError;
+use crate::ids::SuiAddress;
+use crate::retry::Idempotency;
+
+/// `StoreOptions` controls how Blobs and Quilts are stored by the Walrus Publisher service.
+///
+/// All options are optional; unset options fall back to the Publisher's defaults.
+/// The options are validated before a store request is sent, see [`StoreOptions::validate`].
+///
+/// # Example
+/// ```
+/// use walrus_rs::StoreOptions;
+///
+/// let options = StoreOptions::new().epochs(5).permanent(true);
+/// assert!(options.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoreOptions {
+    pub(crate) epochs: Option<u64>,
+    pub(crate) deletable: Option<bool>,
+    pub(crate) permanent: Option<bool>,
+    pub(crate) send_object_to: Option<SuiAddress>,
+}
+
+impl StoreOptions {
+    /// Creates a new `StoreOptions` with all options unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of epochs the data is stored for. Must be greater than zero.
+    pub fn epochs(mut self, epochs: u64) -> Self {
+        self.epochs = Some(epochs);
+        self
+    }
+
+    /// Sets whether the stored Blob can be deleted before its storage expires.
+    ///
+    /// Cannot be combined with `permanent(true)`.
+    pub fn deletable(mut self, deletable: bool) -> Self {
+        self.deletable = Some(deletable);
+        self
+    }
+
+    /// Sets whether the stored Blob is permanent, i.e. cannot be deleted before its storage expires.
+    ///
+    /// Cannot be combined with `deletable(true)`.
+    pub fn permanent(mut self, permanent: bool) -> Self {
+        self.permanent = Some(permanent);
+        self
+    }
+
+    /// Sets the Sui address the created Blob object is sent to.
+    pub fn send_object_to(mut self, address: SuiAddress) -> Self {
+        self.send_object_to = Some(address);
+        self
+    }
+
+    /// Checks that the options are consistent.
+    ///
+    /// # Returns
+    /// - `Ok(())`: The options are valid.
+    /// - `Err(WalrusError::InvalidParameter)`: If the Blob is requested to be both deletable and permanent,
+    ///   or the number of epochs is zero.
+    pub fn validate(&self) -> Result<(), WalrusError> {
+        if self.deletable == Some(true) && self.permanent == Some(true) {
+            return Err(WalrusError::InvalidParameter(
+                "deletable and permanent are mutually exclusive".to_string(),
+            ));
+        }
+        if self.epochs == Some(0) {
+            return Err(WalrusError::InvalidParameter(
+                "epochs must be greater than zero".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the query parameters of a store request.
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(e) = self.epochs {
+            query.push(("epochs", e.to_string()));
+        }
+        if let Some(d) = self.deletable {
+            query.push(("deletable", d.to_string()));
+        }
+        if let Some(p) = self.permanent {
+            query.push(("permanent", p.to_string()));
+        }
+        if let Some(s) = &self.send_object_to {
+            query.push(("send_object_to", s.to_string()));
+        }
+        query
+    }
+
+    /// Determines whether a store request with these options can be repeated safely.
+    ///
+    /// Only stores that are explicitly permanent, i.e. with `permanent(true)` or `deletable(false)`, are
+    /// idempotent: storing the same content again returns the already certified Blob, whereas each deletable store
+    /// creates a new Blob object. Without either flag the Publisher's default applies, which may be deletable.
+    pub(crate) fn idempotency(&self) -> Idempotency {
+        if self.permanent == Some(true) || self.deletable == Some(false) {
+            Idempotency::Idempotent
+        } else {
+            Idempotency::NonIdempotent
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use bytes::Bytes;
+use futures_util::{Stream, TryStreamExt};
+use reqwest::Body;
+
+use crate::error::RequestKind;
+
+/// The size of the chunks in-memory upload bodies are split into, so that progress can be reported while sending.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The direction of a transfer reported in a [`Progress`] update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// Data is sent to a Publisher.
+    Upload,
+    /// Data is received from an Aggregator.
+    Download,
+}
+
+/// A progress update of an upload or download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// The kind of request transferring the data.
+    pub kind: RequestKind,
+    /// The direction of the transfer.
+    pub direction: TransferDirection,
+    /// The number of bytes sent or received so far.
+    pub transferred: u64,
+    /// The total number of bytes, if known.
+    pub total: Option<u64>,
+}
+
+impl Progress {
+    /// Returns the fraction of the transfer completed, between 0 and 1, if the total size is known.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total.map(|total| match total {
+            0 => 1.0,
+            total => self.transferred as f64 / total as f64,
+        })
+    }
+}
+
+/// `ProgressObserver` receives [`Progress`] updates of the uploads and downloads of a client.
+///
+/// It is implemented for all closures taking a `&Progress`. Updates are reported from the tasks performing the
+/// transfer, so observers should return quickly. When a request is retried, progress restarts from zero.
+///
+/// # Example
+/// ```no_run
+/// use walrus_rs::{Progress, WalrusClient};
+///
+/// let client = WalrusClient::builder(
+///     "https://aggregator.testnet.walrus.atalma.io",
+///     "https://publisher.walrus-01.tududes.com",
+/// )
+/// .progress_observer(|progress: &Progress| {
+///     if let Some(fraction) = progress.fraction() {
+///         println!("{:?}: {:.0}%", progress.direction, fraction * 100.0);
+///     }
+/// })
+/// .build()?;
+/// # Ok::<(), walrus_rs::WalrusError>(())
+/// ```
+pub trait ProgressObserver: Send + Sync {
+    /// Called whenever more data of a transfer has been sent or received.
+    fn on_progress(&self, progress: &Progress);
+}
+
+impl<F: Fn(&Progress) + Send + Sync> ProgressObserver for F {
+    fn on_progress(&self, progress: &Progress) {
+        self(progress)
+    }
+}
+
+impl fmt::Debug for dyn ProgressObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressObserver")
+    }
+}
+
+/// Tracks the progress of a single transfer and reports it to an observer.
+#[derive(Clone)]
+pub(crate) struct ProgressTracker {
+    observer: Arc<dyn ProgressObserver>,
+    kind: RequestKind,
+    total: Option<u64>,
+    transferred: Arc<AtomicU64>,
+}
+
+impl ProgressTracker {
+    /// Creates a tracker for a transfer of the given kind and total size.
+    pub(crate) fn new(
+        observer: Arc<dyn ProgressObserver>,
+        kind: RequestKind,
+        total: Option<u64>,
+    ) -> Self {
+        Self {
+            observer,
+            kind,
+            total,
+            transferred: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Restarts the transfer, e.g. when a request is retried, and reports that nothing has been transferred yet.
+    pub(crate) fn restart(&self) {
+        self.transferred.store(0, Ordering::Relaxed);
+        self.report(0);
+    }
+
+    /// Records that `bytes` more bytes have been transferred.
+    fn advance(&self, bytes: u64) {
+        let transferred = self.transferred.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.report(transferred);
+    }
+
+    fn report(&self, transferred: u64) {
+        let direction = match self.kind {
+            RequestKind::StoreBlob | RequestKind::StoreQuilt => TransferDirection::Upload,
+            RequestKind::ReadBlob
+            | RequestKind::ReadQuiltPatch
+            | RequestKind::BlobMetadata
+            | RequestKind::ListQuiltPatches
+            | RequestKind::Status
+            | RequestKind::PublisherStatus => TransferDirection::Download,
+        };
+        self.observer.on_progress(&Progress {
+            kind: self.kind,
+            direction,
+            transferred,
+            total: self.total,
+        });
+    }
+
+    /// Wraps a stream of chunks, reporting the size of each chunk as it passes.
+    pub(crate) fn observe<E>(
+        &self,
+        stream: impl Stream<Item = Result<Bytes, E>> + Send + 'static,
+    ) -> impl Stream<Item = Result<Bytes, E>> + Send + 'static {
+        let tracker = self.clone();
+        stream.inspect_ok(move |chunk| tracker.advance(chunk.len() as u64))
+    }
+
+    /// Creates an upload body from in-memory data, reporting progress as its chunks are sent.
+    pub(crate) fn upload_body(&self, data: Bytes) -> Body {
+        let chunks = (0..data.len())
+            .step_by(UPLOAD_CHUNK_SIZE)
+            .map(move |start| {
+                Ok::<_, std::io::Error>(
+                    data.slice(start..data.len().min(start + UPLOAD_CHUNK_SIZE)),
+                )
+            })
+            .collect::<Vec<_>>();
+        Body::wrap_stream(self.observe(futures_util::stream::iter(chunks)))
+    }
+}
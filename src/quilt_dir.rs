@@ -0,0 +1,365 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+use crate::chunked::hash_hex;
+use crate::encoding::EncodingConfig;
+use crate::error::WalrusError;
+use crate::models::{QuiltMetadata, QuiltPatchItem};
+
+/// The tag holding the MIME type of a file, guessed from its extension.
+pub const CONTENT_TYPE_TAG: &str = "content-type";
+
+/// The tag holding the size of a file in bytes.
+pub const SIZE_TAG: &str = "size";
+
+/// The tag holding the modification time of a file, in seconds since the Unix epoch.
+pub const MODIFIED_TAG: &str = "modified";
+
+/// The tag holding the hex-encoded hash of a file, computed with
+/// [`ChunkManifest::HASH_ALGORITHM`](crate::chunked::ChunkManifest::HASH_ALGORITHM).
+pub const HASH_TAG: &str = "hash";
+
+/// `QuiltDirOptions` controls which files of a directory are stored by
+/// [`WalrusClient::store_quilt_from_dir`](crate::client::WalrusClient::store_quilt_from_dir), and how they are
+/// split into Quilts.
+///
+/// Globs are matched against the path of each file relative to the directory, with `/` as separator. `*` does
+/// not match `/`, whereas `**` matches any number of directories. Symbolic links are skipped unless
+/// [`QuiltDirOptions::follow_symlinks`] is set.
+///
+/// # Example
+/// ```
+/// use walrus_rs::QuiltDirOptions;
+///
+/// let options = QuiltDirOptions::new()
+///     .include("**/*.html")
+///     .include("assets/**")
+///     .exclude("**/.*")
+///     .max_quilt_input_size(4 * 1024 * 1024);
+/// assert!(options.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuiltDirOptions {
+    pub(crate) include: Vec<String>,
+    pub(crate) exclude: Vec<String>,
+    pub(crate) max_files_per_quilt: Option<usize>,
+    pub(crate) max_quilt_input_size: u64,
+    pub(crate) follow_symlinks: bool,
+}
+
+impl Default for QuiltDirOptions {
+    /// Returns options storing every file, in Quilts of files totalling at most
+    /// [`QuiltDirOptions::DEFAULT_MAX_QUILT_INPUT_SIZE`] bytes, holding as many files as the encoding configuration
+    /// allows.
+    fn default() -> Self {
+        Self {
+            include: Vec::new(),
+            exclude: Vec::new(),
+            max_files_per_quilt: None,
+            max_quilt_input_size: Self::DEFAULT_MAX_QUILT_INPUT_SIZE,
+            follow_symlinks: false,
+        }
+    }
+}
+
+impl QuiltDirOptions {
+    /// The default maximum total size of the files of a Quilt of 8 MiB, leaving room for the Quilt index and
+    /// padding below the 10 MiB body limit of public Publishers.
+    pub const DEFAULT_MAX_QUILT_INPUT_SIZE: u64 = 8 * 1024 * 1024;
+
+    /// Creates new `QuiltDirOptions` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a glob selecting files to store. Without any include globs, every file is stored.
+    pub fn include(mut self, glob: impl Into<String>) -> Self {
+        self.include.push(glob.into());
+        self
+    }
+
+    /// Adds a glob selecting files to skip. Directories matching an exclude glob are not walked at all.
+    pub fn exclude(mut self, glob: impl Into<String>) -> Self {
+        self.exclude.push(glob.into());
+        self
+    }
+
+    /// Sets the maximum number of files stored in one Quilt. Must be greater than zero.
+    ///
+    /// Defaults to the most files a Quilt can hold with the encoding configuration of the client, one less than
+    /// the number of columns of the data matrix.
+    pub fn max_files_per_quilt(mut self, max_files_per_quilt: usize) -> Self {
+        self.max_files_per_quilt = Some(max_files_per_quilt);
+        self
+    }
+
+    /// Sets the maximum total size in bytes of the files stored in one Quilt. Must be greater than zero.
+    ///
+    /// This is a budget for the raw contents of the files, not for the size of the resulting Quilt: the Quilt also
+    /// holds an index of the files, and each file is padded to whole columns of the Blob's data matrix. Keep it
+    /// well below the max blob size of the network and the body limit of the Publisher.
+    ///
+    /// Files are read into memory one Quilt at a time, so this also bounds memory usage.
+    pub fn max_quilt_input_size(mut self, max_quilt_input_size: u64) -> Self {
+        self.max_quilt_input_size = max_quilt_input_size;
+        self
+    }
+
+    /// Sets whether symbolic links are followed, storing the files they point to under the path of the link.
+    ///
+    /// Each directory is walked at most once, so links back to a directory already walked are skipped.
+    /// Disabled by default, which skips symbolic links to files and directories alike.
+    pub fn follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Checks that the options are consistent.
+    ///
+    /// # Returns
+    /// - `Ok(())`: The options are valid.
+    /// - `Err(WalrusError::InvalidParameter)`: If a glob is invalid, or a limit is zero.
+    pub fn validate(&self) -> Result<(), WalrusError> {
+        self.globs()?;
+        if self.max_files_per_quilt == Some(0) {
+            return Err(WalrusError::InvalidParameter(
+                "max files per quilt must be greater than zero".to_string(),
+            ));
+        }
+        if self.max_quilt_input_size == 0 || usize::try_from(self.max_quilt_input_size).is_err() {
+            return Err(WalrusError::InvalidParameter(format!(
+                "invalid max quilt input size {}",
+                self.max_quilt_input_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Compiles the include and exclude globs.
+    fn globs(&self) -> Result<(GlobSet, GlobSet), WalrusError> {
+        let build = |globs: &[String]| {
+            let mut builder = GlobSetBuilder::new();
+            for glob in globs {
+                let glob = GlobBuilder::new(glob)
+                    .literal_separator(true)
+                    .build()
+                    .map_err(|e| WalrusError::InvalidParameter(format!("invalid glob: {e}")))?;
+                builder.add(glob);
+            }
+            builder
+                .build()
+                .map_err(|e| WalrusError::InvalidParameter(format!("invalid glob: {e}")))
+        };
+        Ok((build(&self.include)?, build(&self.exclude)?))
+    }
+
+    /// Returns the maximum number of files stored in one Quilt with the given encoding configuration.
+    fn max_files(&self, encoding_config: &EncodingConfig) -> usize {
+        self.max_files_per_quilt.unwrap_or_else(|| {
+            usize::from(encoding_config.source_symbols_secondary().saturating_sub(1)).max(1)
+        })
+    }
+}
+
+/// A file found in a directory stored as Quilts.
+#[derive(Debug)]
+pub(crate) struct DirFile {
+    /// The path of the file relative to the directory, with `/` as separator.
+    pub(crate) identifier: String,
+    /// The path of the file.
+    pub(crate) path: PathBuf,
+    /// The size of the file when the directory was walked.
+    size: u64,
+    /// The modification time of the file, in seconds since the Unix epoch.
+    modified: Option<u64>,
+}
+
+impl DirFile {
+    /// Describes the file with the given contents as Quilt metadata.
+    pub(crate) fn metadata(&self, data: &[u8]) -> QuiltMetadata {
+        let content_type = mime_guess::from_path(&self.path).first_or_octet_stream();
+        let mut tags = HashMap::from([
+            (CONTENT_TYPE_TAG.to_string(), content_type.to_string()),
+            (SIZE_TAG.to_string(), data.len().to_string()),
+            (HASH_TAG.to_string(), hash_hex(data)),
+        ]);
+        if let Some(modified) = self.modified {
+            tags.insert(MODIFIED_TAG.to_string(), modified.to_string());
+        }
+        QuiltMetadata {
+            identifier: self.identifier.clone(),
+            tags,
+        }
+    }
+}
+
+/// Walks a directory and splits the selected files into the batches stored as separate Quilts.
+///
+/// Files are sorted by identifier, and consecutive files are grouped as long as the limits of the options allow.
+///
+/// # Arguments
+/// - `root`: The directory to walk.
+/// - `options`: The [`QuiltDirOptions`] selecting the files and limiting the Quilts.
+/// - `encoding_config`: The encoding configuration used to derive the default number of files per Quilt.
+///
+/// # Returns
+/// - `Ok(Vec<Vec<DirFile>>)`: The non-empty batches of files.
+/// - `Err(WalrusError::InvalidParameter)`: If the options are invalid, no file is selected, a path is not valid
+///   UTF-8 or not an identifier accepted by [`identifier_path`], or a file exceeds the maximum Quilt size.
+/// - `Err(WalrusError::Io)`: If walking the directory failed.
+pub(crate) async fn plan_quilts(
+    root: &Path,
+    options: &QuiltDirOptions,
+    encoding_config: &EncodingConfig,
+) -> Result<Vec<Vec<DirFile>>, WalrusError> {
+    options.validate()?;
+    let (include, exclude) = options.globs()?;
+    let mut files = Vec::new();
+    let mut dirs = vec![(root.to_path_buf(), String::new())];
+    let mut walked = HashSet::new();
+    if options.follow_symlinks {
+        walked.insert(tokio::fs::canonicalize(root).await?);
+    }
+    while let Some((dir, prefix)) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().into_string().map_err(|name| {
+                WalrusError::InvalidParameter(format!(
+                    "file name {name:?} in {} is not valid UTF-8",
+                    dir.display()
+                ))
+            })?;
+            let identifier = format!("{prefix}{name}");
+            if exclude.is_match(&identifier) {
+                continue;
+            }
+            if entry.file_type().await?.is_symlink() && !options.follow_symlinks {
+                continue;
+            }
+            let metadata = tokio::fs::metadata(entry.path()).await?;
+            if metadata.is_dir() {
+                if !options.follow_symlinks
+                    || walked.insert(tokio::fs::canonicalize(entry.path()).await?)
+                {
+                    dirs.push((entry.path(), format!("{identifier}/")));
+                }
+                continue;
+            }
+            if !metadata.is_file() || (!include.is_empty() && !include.is_match(&identifier)) {
+                continue;
+            }
+            // Identifiers that could not be downloaded again are rejected up front.
+            identifier_path(&identifier)?;
+            // The metadata is sent in a multipart part of this name.
+            if identifier == "_metadata" {
+                return Err(WalrusError::InvalidParameter(
+                    "the identifier _metadata is reserved".to_string(),
+                ));
+            }
+            if metadata.len() > options.max_quilt_input_size {
+                return Err(WalrusError::InvalidParameter(format!(
+                    "file {identifier} of {} bytes exceeds the max quilt input size of {} bytes",
+                    metadata.len(),
+                    options.max_quilt_input_size
+                )));
+            }
+            files.push(DirFile {
+                identifier,
+                path: entry.path(),
+                size: metadata.len(),
+                modified: metadata
+                    .modified()
+                    .ok()
+                    .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                    .map(|since| since.as_secs()),
+            });
+        }
+    }
+    if files.is_empty() {
+        return Err(WalrusError::InvalidParameter(format!(
+            "no files to store in {}",
+            root.display()
+        )));
+    }
+    files.sort_by(|a, b| a.identifier.cmp(&b.identifier));
+
+    let max_files = options.max_files(encoding_config);
+    let mut batches: Vec<Vec<DirFile>> = Vec::new();
+    let mut batch_size = 0;
+    for file in files {
+        match batches.last_mut() {
+            Some(batch)
+                if batch.len() < max_files
+                    && batch_size + file.size <= options.max_quilt_input_size =>
+            {
+                batch_size += file.size;
+                batch.push(file);
+            }
+            _ => {
+                batch_size = file.size;
+                batches.push(vec![file]);
+            }
+        }
+    }
+    Ok(batches)
+}
+
+/// Converts the identifier of a Quilt patch to a path relative to the directory the Quilt is downloaded to.
+///
+/// Identifiers are split at `/`, and rejected if they are empty, absolute, or contain components that could
+/// escape the directory, such as `..`.
+///
+/// # Returns
+/// - `Ok(PathBuf)`: The relative path.
+/// - `Err(WalrusError::InvalidParameter)`: If the identifier is not a safe relative path.
+pub(crate) fn identifier_path(identifier: &str) -> Result<PathBuf, WalrusError> {
+    let mut path = PathBuf::new();
+    for component in identifier.split('/') {
+        // Backslashes and colons are separators or drive prefixes on Windows.
+        if matches!(component, "" | "." | "..") || component.contains(['\\', ':', '\0']) {
+            return Err(WalrusError::InvalidParameter(format!(
+                "identifier {identifier:?} is not a safe relative path"
+            )));
+        }
+        path.push(component);
+    }
+    Ok(path)
+}
+
+/// Checks the data read for a Quilt patch against the size reported by the Aggregator and the [`SIZE_TAG`] and
+/// [`HASH_TAG`] tags, as set by [`WalrusClient::store_quilt_from_dir`](crate::client::WalrusClient::store_quilt_from_dir).
+///
+/// Size tags that are not numbers are ignored, as they were not set by this library.
+pub(crate) fn verify_patch(patch: &QuiltPatchItem, data: &[u8]) -> Result<(), WalrusError> {
+    let size = data.len() as u64;
+    let tagged_size = patch.tags.get(SIZE_TAG).and_then(|size| size.parse().ok());
+    if let Some(expected) = patch
+        .size
+        .into_iter()
+        .chain(tagged_size)
+        .find(|&expected| expected != size)
+    {
+        return Err(WalrusError::QuiltPatchMismatch(format!(
+            "{} has {size} bytes instead of {expected}",
+            patch.identifier
+        )));
+    }
+    if let Some(expected) = patch.tags.get(HASH_TAG)
+        && hash_hex(data) != *expected
+    {
+        return Err(WalrusError::QuiltPatchMismatch(format!(
+            "{} does not have the hash {expected}",
+            patch.identifier
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the modification time in the [`MODIFIED_TAG`] tag of a Quilt patch, if any.
+pub(crate) fn modified_time(patch: &QuiltPatchItem) -> Option<SystemTime> {
+    let secs = patch.tags.get(MODIFIED_TAG)?.parse().ok()?;
+    UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+}
@@ -0,0 +1,221 @@
+use std::fmt;
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+use futures_util::StreamExt;
+
+use crate::error::WalrusError;
+use crate::stream::BlobStream;
+
+/// `ByteRange` selects a part of a Blob or Quilt patch to read, as sent in an HTTP `Range` header.
+///
+/// It can be created from Rust ranges over `u64` (`10..20`, `10..=19`, `10..`, `..20`, `..=19`), or with
+/// [`ByteRange::suffix`] to read the last bytes of the data without knowing its length, e.g. a Parquet footer.
+///
+/// # Example
+/// ```
+/// use walrus_rs::ByteRange;
+///
+/// assert_eq!(ByteRange::from(0..1024).to_string(), "bytes=0-1023");
+/// assert_eq!(ByteRange::from(1024..).to_string(), "bytes=1024-");
+/// assert_eq!(ByteRange::suffix(8).to_string(), "bytes=-8");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// The bytes from `start` (inclusive) to `end` (exclusive).
+    Bounded {
+        /// The offset of the first byte.
+        start: u64,
+        /// The offset after the last byte.
+        end: u64,
+    },
+    /// The bytes from the given offset to the end of the data.
+    From(u64),
+    /// The given number of bytes at the end of the data.
+    Suffix(u64),
+}
+
+impl ByteRange {
+    /// Creates a range of the last `length` bytes of the data.
+    pub fn suffix(length: u64) -> Self {
+        ByteRange::Suffix(length)
+    }
+
+    /// Checks that the range selects at least one byte, as required by HTTP.
+    pub(crate) fn validate(&self) -> Result<(), WalrusError> {
+        let empty = match *self {
+            ByteRange::Bounded { start, end } => start >= end,
+            ByteRange::From(_) => false,
+            ByteRange::Suffix(length) => length == 0,
+        };
+        if empty {
+            return Err(WalrusError::InvalidParameter(format!(
+                "Byte range {self:?} is empty"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reads the bytes selected by the range from a partial (`206 Partial Content`) response.
+    ///
+    /// The `Content-Range` of the response must cover the requested bytes; servers may send more, e.g. whole
+    /// blocks, which are cut to the range. Responses with other ranges, or with several ranges in a
+    /// `multipart/byteranges` body, are rejected.
+    ///
+    /// # Arguments
+    /// - `stream`: The body of the response.
+    /// - `content_range`: The `Content-Range` header of the response, if any.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The selected bytes.
+    /// - `Err(WalrusError::ParseError)`: If the response does not contain the selected bytes.
+    pub(crate) async fn read_partial(
+        &self,
+        stream: BlobStream,
+        content_range: Option<&str>,
+    ) -> Result<Vec<u8>, WalrusError> {
+        let invalid = |reason: String| {
+            WalrusError::ParseError(format!("Invalid partial response for {self}: {reason}"))
+        };
+        let (first, last_end, total) = content_range
+            .and_then(parse_content_range)
+            .ok_or_else(|| invalid(format!("unsupported Content-Range {content_range:?}")))?;
+
+        // The requested bytes, clamped to the data if its length is known.
+        let (start, end) = match (*self, total) {
+            (ByteRange::Bounded { start, end }, Some(total)) => (start, end.min(total)),
+            (ByteRange::Bounded { start, end }, None) => (start, end.min(last_end)),
+            (ByteRange::From(start), Some(total)) => (start, total),
+            (ByteRange::From(start), None) => (start, last_end),
+            (ByteRange::Suffix(length), Some(total)) => (total.saturating_sub(length), total),
+            (ByteRange::Suffix(_), None) => {
+                return Err(invalid("unknown length of the data".to_string()));
+            }
+        };
+        if start > end || first > start || last_end < end {
+            return Err(invalid(format!("received bytes {first}-{}", last_end - 1)));
+        }
+
+        let data = stream.collect_bytes().await?;
+        if data.len() as u64 != last_end - first {
+            return Err(invalid(format!(
+                "received {} bytes for bytes {first}-{}",
+                data.len(),
+                last_end - 1
+            )));
+        }
+        Ok(data[(start - first) as usize..(end - first) as usize].to_vec())
+    }
+
+    /// Extracts the bytes selected by the range from a response containing the whole data,
+    /// as sent by servers that ignore the `Range` header.
+    ///
+    /// The stream is consumed only up to the end of the range.
+    pub(crate) async fn slice(&self, mut stream: BlobStream) -> Result<Vec<u8>, WalrusError> {
+        let (start, end) = match *self {
+            ByteRange::Bounded { start, end } => (start, Some(end)),
+            ByteRange::From(start) => (start, None),
+            ByteRange::Suffix(length) => match stream.content_length() {
+                Some(total) => (total.saturating_sub(length), None),
+                None => {
+                    let mut data = stream.collect_bytes().await?;
+                    let start = data.len().saturating_sub(length as usize);
+                    data.drain(..start);
+                    return Ok(data);
+                }
+            },
+        };
+
+        let mut data = Vec::new();
+        let mut offset = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            let chunk_start = offset;
+            offset += chunk.len() as u64;
+            if offset <= start {
+                continue;
+            }
+            let from = start.saturating_sub(chunk_start) as usize;
+            let to = end.map_or(chunk.len(), |end| {
+                (end.saturating_sub(chunk_start) as usize).min(chunk.len())
+            });
+            if from < to {
+                data.extend_from_slice(&chunk[from..to]);
+            }
+            if end.is_some_and(|end| offset >= end) {
+                break;
+            }
+        }
+        Ok(data)
+    }
+}
+
+/// Parses a `Content-Range` header of the form `bytes FIRST-LAST/TOTAL`, where `TOTAL` may be `*`.
+///
+/// Returns the first offset, the offset after the last byte and the total length, if known.
+fn parse_content_range(value: &str) -> Option<(u64, u64, Option<u64>)> {
+    let (range, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+    let (first, last) = range.split_once('-')?;
+    let first: u64 = first.parse().ok()?;
+    let last: u64 = last.parse().ok()?;
+    let total = match total {
+        "*" => None,
+        total => Some(total.parse::<u64>().ok()?),
+    };
+    if first > last || total.is_some_and(|total| last >= total) {
+        return None;
+    }
+    Some((first, last.checked_add(1)?, total))
+}
+
+impl fmt::Display for ByteRange {
+    /// Formats the range as the value of an HTTP `Range` header.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ByteRange::Bounded { start, end } => {
+                write!(f, "bytes={start}-{}", end.saturating_sub(1))
+            }
+            ByteRange::From(start) => write!(f, "bytes={start}-"),
+            ByteRange::Suffix(length) => write!(f, "bytes=-{length}"),
+        }
+    }
+}
+
+impl From<Range<u64>> for ByteRange {
+    fn from(range: Range<u64>) -> Self {
+        ByteRange::Bounded {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl From<RangeInclusive<u64>> for ByteRange {
+    fn from(range: RangeInclusive<u64>) -> Self {
+        let (start, end) = range.into_inner();
+        match end.checked_add(1) {
+            Some(end) => ByteRange::Bounded { start, end },
+            None => ByteRange::From(start),
+        }
+    }
+}
+
+impl From<RangeFrom<u64>> for ByteRange {
+    fn from(range: RangeFrom<u64>) -> Self {
+        ByteRange::From(range.start)
+    }
+}
+
+impl From<RangeTo<u64>> for ByteRange {
+    fn from(range: RangeTo<u64>) -> Self {
+        ByteRange::Bounded {
+            start: 0,
+            end: range.end,
+        }
+    }
+}
+
+impl From<RangeToInclusive<u64>> for ByteRange {
+    fn from(range: RangeToInclusive<u64>) -> Self {
+        ByteRange::from(0..=range.end)
+    }
+}
@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::StatusCode;
+
+/// Describes whether repeating a request can have unintended side effects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Idempotency {
+    /// Repeating the request has no additional effect, e.g. reads, metadata requests and permanent stores.
+    Idempotent,
+    /// Repeating the request may have additional effects, e.g. storing a deletable Blob creates a new Blob object each time.
+    NonIdempotent,
+}
+
+/// `RetryPolicy` controls how failed requests are retried.
+///
+/// Failed attempts are retried with exponential backoff and jitter as long as the failure is considered transient
+/// (a retryable HTTP status code or a retryable `reqwest` error kind) and the maximum number of attempts has not been reached.
+///
+/// Requests that are not idempotent are only retried when the request never reached the server (connection errors),
+/// unless [`RetryPolicy::retry_non_idempotent`] is enabled.
+/// Requests with streaming bodies that cannot be cloned are never retried.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    backoff_multiplier: f64,
+    jitter: f64,
+    retryable_statuses: Vec<StatusCode>,
+    retry_on_timeout: bool,
+    retry_on_connect_error: bool,
+    retry_on_request_error: bool,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Returns a policy with 3 attempts, a backoff starting at 200ms doubling up to 5s with 50% jitter,
+    /// retrying on `408`, `429`, `502`, `503` and `504` as well as timeouts, connection and request errors.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            backoff_multiplier: 2.0,
+            jitter: 0.5,
+            retryable_statuses: vec![
+                StatusCode::REQUEST_TIMEOUT,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_on_timeout: true,
+            retry_on_connect_error: true,
+            retry_on_request_error: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a new `RetryPolicy` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy that never retries.
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Sets the maximum number of attempts, including the first one. Values below 1 are treated as 1.
+    ///
+    /// When a client has several endpoints of a service, each attempt fails over through all of them.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the upper bound of the delay between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Sets the factor the delay is multiplied with after each attempt. Values below 1 are treated as 1.
+    pub fn backoff_multiplier(mut self, multiplier: f64) -> Self {
+        self.backoff_multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Sets the fraction of each delay that is randomized, between `0.0` (no jitter) and `1.0` (full jitter).
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the HTTP status codes that are considered transient.
+    pub fn retryable_statuses(mut self, statuses: Vec<StatusCode>) -> Self {
+        self.retryable_statuses = statuses;
+        self
+    }
+
+    /// Controls whether requests that timed out are retried.
+    pub fn retry_on_timeout(mut self, retry: bool) -> Self {
+        self.retry_on_timeout = retry;
+        self
+    }
+
+    /// Controls whether requests that failed to connect are retried.
+    pub fn retry_on_connect_error(mut self, retry: bool) -> Self {
+        self.retry_on_connect_error = retry;
+        self
+    }
+
+    /// Controls whether requests that failed while being sent (e.g. a reset connection) are retried.
+    pub fn retry_on_request_error(mut self, retry: bool) -> Self {
+        self.retry_on_request_error = retry;
+        self
+    }
+
+    /// Controls whether non-idempotent requests are retried on failures other than connection errors.
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Returns whether a response with the given status should be retried.
+    pub fn is_retryable_status(&self, status: StatusCode, idempotency: Idempotency) -> bool {
+        self.allows(idempotency) && self.retryable_statuses.contains(&status)
+    }
+
+    /// Returns whether the given `reqwest` error should be retried.
+    pub fn is_retryable_error(&self, error: &reqwest::Error, idempotency: Idempotency) -> bool {
+        if let Some(status) = error.status() {
+            return self.is_retryable_status(status, idempotency);
+        }
+        if error.is_connect() {
+            return self.retry_on_connect_error;
+        }
+        if !self.allows(idempotency) {
+            return false;
+        }
+        (error.is_timeout() && self.retry_on_timeout)
+            || (error.is_request() && self.retry_on_request_error)
+    }
+
+    /// Returns the delay to wait after the given failed attempt (starting at 1), including jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = (self.initial_backoff.as_secs_f64() * self.backoff_multiplier.powi(exponent))
+            .min(self.max_backoff.as_secs_f64());
+        let jitter = if self.jitter > 0.0 {
+            rand::rng().random_range(0.0..=self.jitter)
+        } else {
+            0.0
+        };
+        Duration::from_secs_f64(delay * (1.0 - jitter))
+    }
+
+    /// Returns the maximum number of attempts, including the first one.
+    pub(crate) fn attempt_limit(&self) -> u32 {
+        self.max_attempts
+    }
+
+    fn allows(&self, idempotency: Idempotency) -> bool {
+        idempotency == Idempotency::Idempotent || self.retry_non_idempotent
+    }
+}
@@ -0,0 +1,926 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::chunked::hash_hex;
+use crate::client::WalrusClient;
+use crate::encoding::EncodingConfig;
+use crate::error::{ApiError, RequestKind, WalrusError};
+use crate::ids::{BlobId, ObjectId, QuiltPatchId};
+use crate::models::{
+    AlreadyCertified, BlobMetadata, BlobObject, BlobStoreResult, Event, NewlyCreated,
+    QuiltMetadata, QuiltPatchItem, QuiltPatchResponse, QuiltStoreResponse, RegisterFromScratch,
+    ResourceOperation, StorageInfo, StoredQuiltBlob,
+};
+use crate::network::StoragePrices;
+use crate::options::StoreOptions;
+
+/// The number of shards the local stores compute Blob IDs with by default.
+///
+/// Computing Blob IDs for the [`EncodingConfig::DEFAULT_N_SHARDS`] shards of Walrus Mainnet and Testnet takes
+/// seconds per Blob in unoptimized builds, which is too slow for tests.
+pub const LOCAL_N_SHARDS: u16 = 10;
+
+/// The epoch reported as the current one by the local stores.
+pub(crate) const LOCAL_EPOCH: u64 = 1;
+
+/// The storage prices the local stores report costs with, in FROST.
+pub(crate) const LOCAL_PRICES: StoragePrices = StoragePrices {
+    storage_unit_size: StoragePrices::DEFAULT_STORAGE_UNIT_SIZE,
+    storage_price_per_unit_size: 100_000,
+    write_price_per_unit_size: 20_000,
+};
+
+/// The version byte of the Quilt patch IDs assigned by the local stores.
+const QUILT_PATCH_VERSION: u8 = 1;
+
+/// `WalrusStore` abstracts the operations of Walrus storage, so that application code can be written generically
+/// and run against the [`WalrusClient`], the in-memory [`MemoryStore`] in tests, or the [`FileSystemStore`] during
+/// local development.
+///
+/// All implementations report errors like `WalrusClient` does; in particular, missing Blobs and Quilt patches are
+/// reported as [`WalrusError::ApiError`] with status `404 Not Found`.
+///
+/// # Example
+/// ```
+/// use walrus_rs::{MemoryStore, StoreOptions, WalrusError, WalrusStore};
+///
+/// async fn round_trip(store: &dyn WalrusStore) -> Result<Vec<u8>, WalrusError> {
+///     let result = store.store_blob(b"Hello, Walrus!".to_vec(), &StoreOptions::new()).await?;
+///     store.read_blob_by_id(result.blob_id().unwrap()).await
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), WalrusError> {
+/// assert_eq!(round_trip(&MemoryStore::new()).await?, b"Hello, Walrus!");
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait WalrusStore: Send + Sync {
+    /// Stores a Blob. See [`WalrusClient::store_blob`].
+    async fn store_blob(
+        &self,
+        data: Vec<u8>,
+        options: &StoreOptions,
+    ) -> Result<BlobStoreResult, WalrusError>;
+
+    /// Reads a Blob by Blob ID. See [`WalrusClient::read_blob_by_id`].
+    async fn read_blob_by_id(&self, blob_id: &BlobId) -> Result<Vec<u8>, WalrusError>;
+
+    /// Reads a Blob by the ID of its Blob object. See [`WalrusClient::read_blob_by_object_id`].
+    async fn read_blob_by_object_id(&self, object_id: &ObjectId) -> Result<Vec<u8>, WalrusError>;
+
+    /// Retrieves the metadata of a Blob. See [`WalrusClient::get_blob_metadata`].
+    async fn get_blob_metadata(&self, blob_id: &BlobId) -> Result<BlobMetadata, WalrusError>;
+
+    /// Stores a Quilt of multiple files. See [`WalrusClient::store_quilt`].
+    async fn store_quilt(
+        &self,
+        files: Vec<(&str, Vec<u8>)>,
+        metadata: Option<Vec<QuiltMetadata>>,
+        options: &StoreOptions,
+    ) -> Result<QuiltStoreResponse, WalrusError>;
+
+    /// Reads a file of a Quilt by Quilt patch ID. See [`WalrusClient::read_quilt_blob_by_patch_id`].
+    async fn read_quilt_blob_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+    ) -> Result<Vec<u8>, WalrusError>;
+
+    /// Reads a file of a Quilt by Quilt ID and identifier.
+    /// See [`WalrusClient::read_quilt_blob_by_quilt_id_and_identifier`].
+    async fn read_quilt_blob_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<Vec<u8>, WalrusError>;
+
+    /// Reads a file of a Quilt by Quilt patch ID, with its attributes.
+    /// See [`WalrusClient::read_quilt_patch_by_patch_id`].
+    async fn read_quilt_patch_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+    ) -> Result<QuiltPatchResponse, WalrusError>;
+
+    /// Reads a file of a Quilt by Quilt ID and identifier, with its attributes.
+    /// See [`WalrusClient::read_quilt_patch_by_quilt_id_and_identifier`].
+    async fn read_quilt_patch_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<QuiltPatchResponse, WalrusError>;
+
+    /// Lists the patches of a Quilt. See [`WalrusClient::list_quilt_patches`].
+    async fn list_quilt_patches(
+        &self,
+        quilt_id: &BlobId,
+    ) -> Result<Vec<QuiltPatchItem>, WalrusError>;
+}
+
+#[async_trait]
+impl WalrusStore for WalrusClient {
+    async fn store_blob(
+        &self,
+        data: Vec<u8>,
+        options: &StoreOptions,
+    ) -> Result<BlobStoreResult, WalrusError> {
+        WalrusClient::store_blob(self, data, options).await
+    }
+
+    async fn read_blob_by_id(&self, blob_id: &BlobId) -> Result<Vec<u8>, WalrusError> {
+        WalrusClient::read_blob_by_id(self, blob_id).await
+    }
+
+    async fn read_blob_by_object_id(&self, object_id: &ObjectId) -> Result<Vec<u8>, WalrusError> {
+        WalrusClient::read_blob_by_object_id(self, object_id).await
+    }
+
+    async fn get_blob_metadata(&self, blob_id: &BlobId) -> Result<BlobMetadata, WalrusError> {
+        WalrusClient::get_blob_metadata(self, blob_id).await
+    }
+
+    async fn store_quilt(
+        &self,
+        files: Vec<(&str, Vec<u8>)>,
+        metadata: Option<Vec<QuiltMetadata>>,
+        options: &StoreOptions,
+    ) -> Result<QuiltStoreResponse, WalrusError> {
+        WalrusClient::store_quilt(self, files, metadata, options).await
+    }
+
+    async fn read_quilt_blob_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+    ) -> Result<Vec<u8>, WalrusError> {
+        WalrusClient::read_quilt_blob_by_patch_id(self, quilt_patch_id).await
+    }
+
+    async fn read_quilt_blob_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<Vec<u8>, WalrusError> {
+        WalrusClient::read_quilt_blob_by_quilt_id_and_identifier(self, quilt_id, identifier).await
+    }
+
+    async fn read_quilt_patch_by_patch_id(
+        &self,
+        quilt_patch_id: &QuiltPatchId,
+    ) -> Result<QuiltPatchResponse, WalrusError> {
+        WalrusClient::read_quilt_patch_by_patch_id(self, quilt_patch_id).await
+    }
+
+    async fn read_quilt_patch_by_quilt_id_and_identifier(
+        &self,
+        quilt_id: &BlobId,
+        identifier: &str,
+    ) -> Result<QuiltPatchResponse, WalrusError> {
+        WalrusClient::read_quilt_patch_by_quilt_id_and_identifier(self, quilt_id, identifier).await
+    }
+
+    async fn list_quilt_patches(
+        &self,
+        quilt_id: &BlobId,
+    ) -> Result<Vec<QuiltPatchItem>, WalrusError> {
+        WalrusClient::list_quilt_patches(self, quilt_id).await
+    }
+}
+
+/// `MemoryStore` is a [`WalrusStore`] keeping Blobs in memory, e.g. as a fake in tests.
+///
+/// It follows the semantics of a Publisher: Blob IDs are computed with its [`EncodingConfig`], storing a Blob
+/// creates a new Blob object unless it is permanent and already certified for long enough, and store results
+/// report made-up object IDs and costs. Epochs do not advance, so stored Blobs never expire.
+#[derive(Debug)]
+pub struct MemoryStore {
+    encoding_config: EncodingConfig,
+    state: Mutex<MemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    blobs: HashMap<BlobId, (Vec<u8>, BlobRecord)>,
+    objects: HashMap<ObjectId, BlobId>,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::with_encoding_config(
+            EncodingConfig::new(LOCAL_N_SHARDS).expect("the number of local shards is valid"),
+        )
+    }
+}
+
+impl MemoryStore {
+    /// Creates an empty `MemoryStore` computing Blob IDs with [`LOCAL_N_SHARDS`] shards.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `MemoryStore` computing Blob IDs with the given encoding configuration.
+    pub fn with_encoding_config(encoding_config: EncodingConfig) -> Self {
+        Self {
+            encoding_config,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Returns the encoding configuration used to compute Blob IDs.
+    pub fn encoding_config(&self) -> &EncodingConfig {
+        &self.encoding_config
+    }
+
+    /// Stores a permanent Blob directly, without creating a Blob object, and returns its Blob ID.
+    ///
+    /// # Returns
+    /// - `Ok(BlobId)`: The Blob ID of the data.
+    /// - `Err(WalrusError::InvalidParameter)`: If the data is too large to be encoded.
+    pub fn insert_blob(&self, data: impl Into<Vec<u8>>) -> Result<BlobId, WalrusError> {
+        let data = data.into();
+        let blob_id = self.encoding_config.compute_blob_id(&data)?;
+        let record = BlobRecord {
+            end_epoch: LOCAL_EPOCH + 1,
+            deletable: false,
+        };
+        let mut state = self.state.lock().unwrap();
+        let (_, existing) = state.blobs.entry(blob_id).or_insert((data, record));
+        existing.end_epoch = existing.end_epoch.max(record.end_epoch);
+        existing.deletable = false;
+        Ok(blob_id)
+    }
+
+    /// Returns the data of a stored Blob.
+    pub fn blob(&self, blob_id: &BlobId) -> Option<Vec<u8>> {
+        let state = self.state.lock().unwrap();
+        state.blobs.get(blob_id).map(|(data, _)| data.clone())
+    }
+
+    /// Returns the number of distinct Blobs stored, including Quilts.
+    pub fn blob_count(&self) -> usize {
+        self.state.lock().unwrap().blobs.len()
+    }
+}
+
+#[async_trait]
+impl LocalBackend for MemoryStore {
+    fn encoding_config(&self) -> &EncodingConfig {
+        &self.encoding_config
+    }
+
+    fn url(&self, path: &str) -> Url {
+        Url::parse("memory:///")
+            .and_then(|base| base.join(path))
+            .expect("API paths are valid URL paths")
+    }
+
+    async fn load_blob(&self, blob_id: &BlobId) -> Result<Option<Vec<u8>>, WalrusError> {
+        let state = self.state.lock().unwrap();
+        Ok(state.blobs.get(blob_id).map(|(data, _)| data.clone()))
+    }
+
+    async fn load_record(&self, blob_id: &BlobId) -> Result<Option<BlobRecord>, WalrusError> {
+        let state = self.state.lock().unwrap();
+        Ok(state.blobs.get(blob_id).map(|(_, record)| *record))
+    }
+
+    async fn save_blob(
+        &self,
+        blob_id: &BlobId,
+        data: &[u8],
+        record: BlobRecord,
+    ) -> Result<(), WalrusError> {
+        let mut state = self.state.lock().unwrap();
+        state
+            .blobs
+            .entry(*blob_id)
+            .and_modify(|(_, existing)| *existing = record)
+            .or_insert_with(|| (data.to_vec(), record));
+        Ok(())
+    }
+
+    async fn load_object(&self, object_id: &ObjectId) -> Result<Option<BlobId>, WalrusError> {
+        Ok(self.state.lock().unwrap().objects.get(object_id).copied())
+    }
+
+    async fn save_object(&self, object_id: &ObjectId, blob_id: &BlobId) -> Result<(), WalrusError> {
+        let mut state = self.state.lock().unwrap();
+        state.objects.insert(*object_id, *blob_id);
+        Ok(())
+    }
+}
+
+/// `FileSystemStore` is a [`WalrusStore`] keeping Blobs as files in a directory, e.g. for local development.
+///
+/// It follows the same semantics as [`MemoryStore`]. The directory contains a `blobs` directory with one file per
+/// Blob, named by its Blob ID, next to a JSON file recording its storage, and an `objects` directory mapping
+/// Blob object IDs to Blob IDs.
+#[derive(Debug, Clone)]
+pub struct FileSystemStore {
+    root: PathBuf,
+    base_url: Url,
+    encoding_config: EncodingConfig,
+}
+
+impl FileSystemStore {
+    /// Opens the store in the given directory, creating it if it does not exist.
+    /// Blob IDs are computed with [`LOCAL_N_SHARDS`] shards.
+    ///
+    /// # Returns
+    /// - `Ok(FileSystemStore)`: The opened store.
+    /// - `Err(WalrusError::Io)`: If the directory could not be created.
+    pub fn open(root: impl AsRef<Path>) -> Result<Self, WalrusError> {
+        Self::open_with_encoding_config(
+            root,
+            EncodingConfig::new(LOCAL_N_SHARDS).expect("the number of local shards is valid"),
+        )
+    }
+
+    /// Opens the store in the given directory, computing Blob IDs with the given encoding configuration.
+    ///
+    /// Blobs are looked up by Blob ID, so a store must always be opened with the same configuration.
+    ///
+    /// # Returns
+    /// - `Ok(FileSystemStore)`: The opened store.
+    /// - `Err(WalrusError::Io)`: If the directory could not be created.
+    pub fn open_with_encoding_config(
+        root: impl AsRef<Path>,
+        encoding_config: EncodingConfig,
+    ) -> Result<Self, WalrusError> {
+        let root = std::path::absolute(root.as_ref())?;
+        for directory in [Self::BLOBS, Self::OBJECTS] {
+            std::fs::create_dir_all(root.join(directory))?;
+        }
+        let base_url = Url::from_directory_path(&root).map_err(|()| {
+            WalrusError::InvalidParameter(format!("Invalid store directory {}", root.display()))
+        })?;
+        Ok(Self {
+            root,
+            base_url,
+            encoding_config,
+        })
+    }
+
+    /// The directory containing the Blobs.
+    const BLOBS: &'static str = "blobs";
+
+    /// The directory containing the Blob objects.
+    const OBJECTS: &'static str = "objects";
+
+    /// Returns the encoding configuration used to compute Blob IDs.
+    pub fn encoding_config(&self) -> &EncodingConfig {
+        &self.encoding_config
+    }
+
+    /// Returns the directory of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn blob_path(&self, blob_id: &BlobId) -> PathBuf {
+        self.root.join(Self::BLOBS).join(blob_id.to_string())
+    }
+
+    fn record_path(&self, blob_id: &BlobId) -> PathBuf {
+        self.root.join(Self::BLOBS).join(format!("{blob_id}.json"))
+    }
+
+    fn object_path(&self, object_id: &ObjectId) -> PathBuf {
+        self.root.join(Self::OBJECTS).join(object_id.to_string())
+    }
+}
+
+#[async_trait]
+impl LocalBackend for FileSystemStore {
+    fn encoding_config(&self) -> &EncodingConfig {
+        &self.encoding_config
+    }
+
+    fn url(&self, path: &str) -> Url {
+        self.base_url
+            .join(path)
+            .expect("API paths are valid URL paths")
+    }
+
+    async fn load_blob(&self, blob_id: &BlobId) -> Result<Option<Vec<u8>>, WalrusError> {
+        read_optional(&self.blob_path(blob_id)).await
+    }
+
+    async fn load_record(&self, blob_id: &BlobId) -> Result<Option<BlobRecord>, WalrusError> {
+        read_optional(&self.record_path(blob_id))
+            .await?
+            .map(|record| {
+                serde_json::from_slice(&record).map_err(|e| {
+                    WalrusError::ParseError(format!(
+                        "Failed to parse record of blob {blob_id}: {e}"
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    async fn save_blob(
+        &self,
+        blob_id: &BlobId,
+        data: &[u8],
+        record: BlobRecord,
+    ) -> Result<(), WalrusError> {
+        let path = self.blob_path(blob_id);
+        if !tokio::fs::try_exists(&path).await? {
+            write_atomically(&path, data).await?;
+        }
+        let record = serde_json::to_vec(&record).expect("a blob record is always serializable");
+        write_atomically(&self.record_path(blob_id), &record).await
+    }
+
+    async fn load_object(&self, object_id: &ObjectId) -> Result<Option<BlobId>, WalrusError> {
+        read_optional(&self.object_path(object_id))
+            .await?
+            .map(|blob_id| String::from_utf8_lossy(&blob_id).trim().parse())
+            .transpose()
+    }
+
+    async fn save_object(&self, object_id: &ObjectId, blob_id: &BlobId) -> Result<(), WalrusError> {
+        write_atomically(&self.object_path(object_id), blob_id.to_string().as_bytes()).await
+    }
+}
+
+/// Reads a file, returning `None` if it does not exist.
+async fn read_optional(path: &Path) -> Result<Option<Vec<u8>>, WalrusError> {
+    match tokio::fs::read(path).await {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Writes a file through a temporary file, so that readers never see it partially written.
+async fn write_atomically(path: &Path, data: &[u8]) -> Result<(), WalrusError> {
+    let temporary = path.with_extension(format!("tmp-{:016x}", rand::random::<u64>()));
+    tokio::fs::write(&temporary, data).await?;
+    if let Err(e) = tokio::fs::rename(&temporary, path).await {
+        let _ = tokio::fs::remove_file(&temporary).await;
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// The storage of a Blob kept by a local store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlobRecord {
+    end_epoch: u64,
+    deletable: bool,
+}
+
+/// The storage primitives of the local stores, on which the Walrus semantics are implemented.
+#[async_trait]
+trait LocalBackend: Send + Sync {
+    /// Returns the encoding configuration used to compute Blob IDs.
+    fn encoding_config(&self) -> &EncodingConfig;
+
+    /// Returns the URL reported in errors of requests for the given API path.
+    fn url(&self, path: &str) -> Url;
+
+    async fn load_blob(&self, blob_id: &BlobId) -> Result<Option<Vec<u8>>, WalrusError>;
+
+    async fn load_record(&self, blob_id: &BlobId) -> Result<Option<BlobRecord>, WalrusError>;
+
+    /// Saves a Blob with its storage, keeping the data if the Blob already exists.
+    async fn save_blob(
+        &self,
+        blob_id: &BlobId,
+        data: &[u8],
+        record: BlobRecord,
+    ) -> Result<(), WalrusError>;
+
+    async fn load_object(&self, object_id: &ObjectId) -> Result<Option<BlobId>, WalrusError>;
+
+    async fn save_object(&self, object_id: &ObjectId, blob_id: &BlobId) -> Result<(), WalrusError>;
+}
+
+/// Implements [`WalrusStore`] for a type implementing [`LocalBackend`].
+macro_rules! impl_local_store {
+    ($store:ty) => {
+        #[async_trait]
+        impl WalrusStore for $store {
+            async fn store_blob(
+                &self,
+                data: Vec<u8>,
+                options: &StoreOptions,
+            ) -> Result<BlobStoreResult, WalrusError> {
+                store_local_blob(self, data, options).await
+            }
+
+            async fn read_blob_by_id(&self, blob_id: &BlobId) -> Result<Vec<u8>, WalrusError> {
+                read_local_blob(self, RequestKind::ReadBlob, blob_id).await
+            }
+
+            async fn read_blob_by_object_id(
+                &self,
+                object_id: &ObjectId,
+            ) -> Result<Vec<u8>, WalrusError> {
+                let path = format!("v1/blobs/by-object-id/{object_id}");
+                match self.load_object(object_id).await? {
+                    Some(blob_id) => read_local_blob(self, RequestKind::ReadBlob, &blob_id).await,
+                    None => Err(not_found(self, RequestKind::ReadBlob, &path)),
+                }
+            }
+
+            async fn get_blob_metadata(
+                &self,
+                blob_id: &BlobId,
+            ) -> Result<BlobMetadata, WalrusError> {
+                let data = read_local_blob(self, RequestKind::BlobMetadata, blob_id).await?;
+                Ok(BlobMetadata {
+                    content_length: data.len() as u64,
+                    content_type: "application/octet-stream".to_string(),
+                    etag: format!("\"{}\"", hash_hex(&data)),
+                })
+            }
+
+            async fn store_quilt(
+                &self,
+                files: Vec<(&str, Vec<u8>)>,
+                metadata: Option<Vec<QuiltMetadata>>,
+                options: &StoreOptions,
+            ) -> Result<QuiltStoreResponse, WalrusError> {
+                store_local_quilt(self, files, metadata, options).await
+            }
+
+            async fn read_quilt_blob_by_patch_id(
+                &self,
+                quilt_patch_id: &QuiltPatchId,
+            ) -> Result<Vec<u8>, WalrusError> {
+                let patch = WalrusStore::read_quilt_patch_by_patch_id(self, quilt_patch_id).await?;
+                Ok(patch.data)
+            }
+
+            async fn read_quilt_blob_by_quilt_id_and_identifier(
+                &self,
+                quilt_id: &BlobId,
+                identifier: &str,
+            ) -> Result<Vec<u8>, WalrusError> {
+                let patch = WalrusStore::read_quilt_patch_by_quilt_id_and_identifier(
+                    self, quilt_id, identifier,
+                )
+                .await?;
+                Ok(patch.data)
+            }
+
+            async fn read_quilt_patch_by_patch_id(
+                &self,
+                quilt_patch_id: &QuiltPatchId,
+            ) -> Result<QuiltPatchResponse, WalrusError> {
+                let path = format!("v1/blobs/by-quilt-patch-id/{quilt_patch_id}");
+                let index = match *quilt_patch_id.patch_bytes() {
+                    [QUILT_PATCH_VERSION, low, high, ..] => {
+                        usize::from(u16::from_le_bytes([low, high]))
+                    }
+                    _ => return Err(not_found(self, RequestKind::ReadQuiltPatch, &path)),
+                };
+                let patches = read_local_quilt(
+                    self,
+                    RequestKind::ReadQuiltPatch,
+                    quilt_patch_id.quilt_id(),
+                    &path,
+                )
+                .await?;
+                patches
+                    .into_iter()
+                    .nth(index)
+                    .map(LocalPatch::into_response)
+                    .ok_or_else(|| not_found(self, RequestKind::ReadQuiltPatch, &path))
+            }
+
+            async fn read_quilt_patch_by_quilt_id_and_identifier(
+                &self,
+                quilt_id: &BlobId,
+                identifier: &str,
+            ) -> Result<QuiltPatchResponse, WalrusError> {
+                let path = format!("v1/blobs/by-quilt-id/{quilt_id}/{identifier}");
+                let patches =
+                    read_local_quilt(self, RequestKind::ReadQuiltPatch, quilt_id, &path).await?;
+                patches
+                    .into_iter()
+                    .find(|patch| patch.identifier == identifier)
+                    .map(LocalPatch::into_response)
+                    .ok_or_else(|| not_found(self, RequestKind::ReadQuiltPatch, &path))
+            }
+
+            async fn list_quilt_patches(
+                &self,
+                quilt_id: &BlobId,
+            ) -> Result<Vec<QuiltPatchItem>, WalrusError> {
+                let path = format!("v1/quilts/{quilt_id}/patches");
+                let patches =
+                    read_local_quilt(self, RequestKind::ListQuiltPatches, quilt_id, &path).await?;
+                Ok(patches
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, patch)| QuiltPatchItem {
+                        quilt_patch_id: local_patch_id(quilt_id, index),
+                        size: Some(patch.data.len() as u64),
+                        identifier: patch.identifier,
+                        tags: patch.tags,
+                    })
+                    .collect())
+            }
+        }
+    };
+}
+
+impl_local_store!(MemoryStore);
+impl_local_store!(FileSystemStore);
+
+/// Stores a Blob in a local store, describing the result as a Publisher would.
+async fn store_local_blob(
+    backend: &impl LocalBackend,
+    data: Vec<u8>,
+    options: &StoreOptions,
+) -> Result<BlobStoreResult, WalrusError> {
+    options.validate()?;
+    let epochs = options.epochs.unwrap_or(1);
+    let deletable = match (options.deletable, options.permanent) {
+        (Some(deletable), _) => deletable,
+        (None, Some(permanent)) => !permanent,
+        (None, None) => true,
+    };
+    let config = *backend.encoding_config();
+    let (blob_id, data) = tokio::task::spawn_blocking(move || {
+        config.compute_blob_id(&data).map(|blob_id| (blob_id, data))
+    })
+    .await
+    .map_err(|e| WalrusError::Other(format!("Blob ID computation failed: {e}")))??;
+    let size = data.len() as u64;
+    let end_epoch = LOCAL_EPOCH + epochs;
+
+    let existing = backend.load_record(&blob_id).await?;
+    if let Some(record) = existing
+        && !deletable
+        && !record.deletable
+        && record.end_epoch >= end_epoch
+    {
+        return Ok(BlobStoreResult::AlreadyCertified(AlreadyCertified {
+            blob_id,
+            event: Some(Event {
+                tx_digest: random_digest(),
+                event_seq: "0".to_string(),
+            }),
+            object: None,
+            end_epoch: record.end_epoch,
+        }));
+    }
+
+    let record = BlobRecord {
+        end_epoch: existing.map_or(end_epoch, |record| record.end_epoch.max(end_epoch)),
+        deletable: existing.is_none_or(|record| record.deletable) && deletable,
+    };
+    backend.save_blob(&blob_id, &data, record).await?;
+    let object_id = ObjectId::new(rand::random());
+    backend.save_object(&object_id, &blob_id).await?;
+
+    let encoded_length = config.encoded_blob_length(size)?;
+    let cost = LOCAL_PRICES
+        .estimate(encoded_length, epochs)?
+        .total_cost()?;
+    Ok(BlobStoreResult::NewlyCreated(NewlyCreated {
+        blob_object: BlobObject {
+            id: object_id,
+            registered_epoch: LOCAL_EPOCH,
+            blob_id,
+            size,
+            encoding_type: "RS2".to_string(),
+            certified_epoch: Some(LOCAL_EPOCH),
+            storage: StorageInfo {
+                id: ObjectId::new(rand::random()),
+                start_epoch: LOCAL_EPOCH,
+                end_epoch,
+                storage_size: encoded_length,
+            },
+            deletable,
+        },
+        resource_operation: ResourceOperation {
+            register_from_scratch: Some(RegisterFromScratch {
+                encoded_length,
+                epochs_ahead: epochs,
+            }),
+        },
+        cost,
+        shared_blob_object: None,
+    }))
+}
+
+/// Reads a Blob from a local store.
+async fn read_local_blob(
+    backend: &impl LocalBackend,
+    kind: RequestKind,
+    blob_id: &BlobId,
+) -> Result<Vec<u8>, WalrusError> {
+    backend
+        .load_blob(blob_id)
+        .await?
+        .ok_or_else(|| not_found(backend, kind, &format!("v1/blobs/{blob_id}")))
+}
+
+/// Stores a Quilt in a local store as a single Blob containing all files.
+///
+/// The Blob is encoded differently from real Quilts, so the Quilt ID differs from the one assigned by Walrus.
+async fn store_local_quilt(
+    backend: &impl LocalBackend,
+    files: Vec<(&str, Vec<u8>)>,
+    metadata: Option<Vec<QuiltMetadata>>,
+    options: &StoreOptions,
+) -> Result<QuiltStoreResponse, WalrusError> {
+    options.validate()?;
+    if files.is_empty() {
+        return Err(WalrusError::InvalidParameter(
+            "A Quilt must contain at least one file".to_string(),
+        ));
+    }
+    let mut identifiers = HashSet::new();
+    if let Some((identifier, _)) = files
+        .iter()
+        .find(|(identifier, _)| !identifiers.insert(*identifier))
+    {
+        return Err(WalrusError::InvalidParameter(format!(
+            "Duplicate Quilt identifier {identifier:?}"
+        )));
+    }
+    let mut tags: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for entry in metadata.unwrap_or_default() {
+        if !identifiers.contains(entry.identifier.as_str()) {
+            return Err(WalrusError::InvalidParameter(format!(
+                "Metadata refers to unknown Quilt identifier {:?}",
+                entry.identifier
+            )));
+        }
+        tags.insert(entry.identifier, entry.tags);
+    }
+
+    let patches: Vec<LocalPatch> = files
+        .into_iter()
+        .map(|(identifier, data)| LocalPatch {
+            identifier: identifier.to_string(),
+            tags: tags.remove(identifier).unwrap_or_default(),
+            data,
+        })
+        .collect();
+    if patches.len() > usize::from(u16::MAX) {
+        return Err(WalrusError::InvalidParameter(format!(
+            "A Quilt can contain at most {} files",
+            u16::MAX
+        )));
+    }
+    let blob_store_result = store_local_blob(backend, encode_quilt(&patches), options).await?;
+    let quilt_id = *blob_store_result
+        .blob_id()
+        .expect("local stores always return the Blob ID of a stored Blob");
+
+    let stored_quilt_blobs = patches
+        .into_iter()
+        .enumerate()
+        .map(|(index, patch)| StoredQuiltBlob {
+            identifier: patch.identifier,
+            quilt_patch_id: local_patch_id(&quilt_id, index),
+        })
+        .collect();
+    Ok(QuiltStoreResponse {
+        blob_store_result,
+        stored_quilt_blobs,
+    })
+}
+
+/// Returns the Quilt Patch ID local stores assign to the patch at the given index of a Quilt.
+fn local_patch_id(quilt_id: &BlobId, index: usize) -> QuiltPatchId {
+    let index = index as u16;
+    let mut patch_bytes = vec![QUILT_PATCH_VERSION];
+    patch_bytes.extend_from_slice(&index.to_le_bytes());
+    patch_bytes.extend_from_slice(&(index + 1).to_le_bytes());
+    QuiltPatchId::new(*quilt_id, patch_bytes).expect("the patch bytes of local stores are valid")
+}
+
+/// Reads the patches of a Quilt from a local store.
+async fn read_local_quilt(
+    backend: &impl LocalBackend,
+    kind: RequestKind,
+    quilt_id: &BlobId,
+    path: &str,
+) -> Result<Vec<LocalPatch>, WalrusError> {
+    backend
+        .load_blob(quilt_id)
+        .await?
+        .and_then(|quilt| decode_quilt(&quilt))
+        .ok_or_else(|| not_found(backend, kind, path))
+}
+
+/// A file of a Quilt kept by a local store.
+struct LocalPatch {
+    identifier: String,
+    tags: HashMap<String, String>,
+    data: Vec<u8>,
+}
+
+impl LocalPatch {
+    /// Describes the patch as an Aggregator would, with its identifier and tags in the response headers.
+    ///
+    /// Tags that are not valid header names or values are left out of the headers, but all tags except
+    /// `content-type` are reported as the tags of the response.
+    fn into_response(mut self) -> QuiltPatchResponse {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/octet-stream"),
+        );
+        headers.insert(CONTENT_LENGTH, HeaderValue::from(self.data.len()));
+        if let Ok(identifier) = HeaderValue::from_str(&self.identifier) {
+            headers.insert(QuiltPatchResponse::IDENTIFIER_HEADER, identifier);
+        }
+        for (key, value) in &self.tags {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(key.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.insert(name, value);
+            }
+        }
+        self.tags.remove(CONTENT_TYPE.as_str());
+        let mut response = QuiltPatchResponse::from_parts(self.data, headers, &[]);
+        response.identifier = Some(self.identifier);
+        response.tags = self.tags;
+        response
+    }
+}
+
+/// Encodes the patches of a Quilt as a sequence of length-prefixed identifiers, JSON-encoded tags and data.
+fn encode_quilt(patches: &[LocalPatch]) -> Vec<u8> {
+    let mut quilt = Vec::new();
+    for patch in patches {
+        let tags = serde_json::to_vec(&patch.tags).expect("tags are always serializable");
+        for field in [patch.identifier.as_bytes(), &tags, &patch.data] {
+            quilt.extend_from_slice(&(field.len() as u64).to_le_bytes());
+            quilt.extend_from_slice(field);
+        }
+    }
+    quilt
+}
+
+/// Decodes the patches of a Quilt encoded by [`encode_quilt`], or returns `None` if the data is not a Quilt.
+fn decode_quilt(mut quilt: &[u8]) -> Option<Vec<LocalPatch>> {
+    let mut field = || {
+        let (length, rest) = quilt.split_first_chunk::<8>()?;
+        let length = usize::try_from(u64::from_le_bytes(*length)).ok()?;
+        let (field, rest) = rest.split_at_checked(length)?;
+        quilt = rest;
+        Some(field)
+    };
+    let mut patches = Vec::new();
+    while let Some(identifier) = field() {
+        let identifier = String::from_utf8(identifier.to_vec()).ok()?;
+        let tags = serde_json::from_slice(field()?).ok()?;
+        let data = field()?.to_vec();
+        patches.push(LocalPatch {
+            identifier,
+            tags,
+            data,
+        });
+    }
+    (quilt.is_empty() && !patches.is_empty()).then_some(patches)
+}
+
+/// Creates the error reported by local stores for missing Blobs and Quilt patches, as a Walrus Aggregator would.
+fn not_found(backend: &impl LocalBackend, kind: RequestKind, path: &str) -> WalrusError {
+    let body = serde_json::json!({
+        "error": {
+            "code": 404,
+            "status": "NOT_FOUND",
+            "message": "the requested blob does not exist",
+            "details": [{
+                "@type": "ErrorInfo",
+                "reason": "BLOB_NOT_FOUND",
+                "domain": "daemon.walrus.space",
+                "metadata": {},
+            }],
+        }
+    });
+    ApiError::new(
+        StatusCode::NOT_FOUND,
+        kind,
+        backend.url(path),
+        &body.to_string(),
+    )
+    .into()
+}
+
+/// Returns a random base58-like transaction digest.
+fn random_digest() -> String {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    (0..44)
+        .map(|_| char::from(ALPHABET[rand::random_range(0..ALPHABET.len())]))
+        .collect()
+}
@@ -0,0 +1,96 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt, TryStreamExt};
+use tokio::io::AsyncRead;
+use tokio_util::io::StreamReader;
+
+use crate::error::WalrusError;
+
+/// `BlobStream` is a stream of Blob data chunks as they are received from the Walrus Aggregator service.
+///
+/// It allows processing Blobs of any size with bounded memory, e.g. piping them to disk or into an HTTP response.
+/// Use [`BlobStream::into_async_read`] to consume it as a `tokio::io::AsyncRead`.
+pub struct BlobStream {
+    inner: Pin<Box<dyn Stream<Item = Result<Bytes, WalrusError>> + Send>>,
+    content_length: Option<u64>,
+}
+
+impl BlobStream {
+    /// The most memory [`BlobStream::collect_bytes`] allocates before receiving any data, 1 MiB.
+    pub const MAX_PREALLOCATION: usize = 1024 * 1024;
+
+    /// Creates a `BlobStream` from a stream of chunks.
+    ///
+    /// # Arguments
+    /// - `stream`: The stream of data chunks.
+    /// - `content_length`: Optional, the total length of the data if known in advance.
+    pub fn new(
+        stream: impl Stream<Item = Result<Bytes, WalrusError>> + Send + 'static,
+        content_length: Option<u64>,
+    ) -> Self {
+        Self {
+            inner: Box::pin(stream),
+            content_length,
+        }
+    }
+
+    /// Creates a `BlobStream` from the body of an HTTP response.
+    pub(crate) fn from_response(response: reqwest::Response) -> Self {
+        let content_length = response.content_length();
+        let stream = response
+            .bytes_stream()
+            .map_err(|e| WalrusError::ParseError(format!("Failed to read blob bytes: {e}")));
+        Self::new(stream, content_length)
+    }
+
+    /// Returns the total length of the data, if announced by the server.
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// Converts the stream into a `tokio::io::AsyncRead`.
+    ///
+    /// Errors of the stream are returned as `std::io::Error` wrapping the [`WalrusError`].
+    pub fn into_async_read(self) -> impl AsyncRead + Send + Unpin {
+        StreamReader::new(self.map_err(io::Error::other))
+    }
+
+    /// Collects the whole stream into memory.
+    ///
+    /// The announced length only sizes the initial buffer up to [`BlobStream::MAX_PREALLOCATION`], so that a
+    /// server announcing a huge length cannot make the client allocate memory for data it never sends.
+    pub async fn collect_bytes(mut self) -> Result<Vec<u8>, WalrusError> {
+        let capacity = self
+            .content_length
+            .unwrap_or(0)
+            .min(Self::MAX_PREALLOCATION as u64);
+        let mut data = Vec::with_capacity(capacity as usize);
+        while let Some(chunk) = self.next().await {
+            data.extend_from_slice(&chunk?);
+        }
+        Ok(data)
+    }
+}
+
+impl Stream for BlobStream {
+    type Item = Result<Bytes, WalrusError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl std::fmt::Debug for BlobStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlobStream")
+            .field("content_length", &self.content_length)
+            .finish_non_exhaustive()
+    }
+}
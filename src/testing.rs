@@ -0,0 +1,720 @@
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::net::TcpListener as StdTcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+use bytes::Bytes;
+use futures_util::StreamExt;
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Full, StreamBody};
+use hyper::body::{Frame, Incoming};
+use hyper::header::{CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG, HeaderMap, RANGE};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use percent_encoding::percent_decode_str;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+use crate::blocking_client::BlockingWalrusClient;
+use crate::builder::WalrusClientBuilder;
+use crate::chunked::hash_hex;
+use crate::client::WalrusClient;
+use crate::encoding::EncodingConfig;
+use crate::error::WalrusError;
+use crate::ids::BlobId;
+use crate::models::{QuiltMetadata, QuiltPatchResponse};
+use crate::network::{NetworkInfo, StoragePrices};
+use crate::options::StoreOptions;
+use crate::store::{LOCAL_EPOCH, LOCAL_N_SHARDS, LOCAL_PRICES, MemoryStore, WalrusStore};
+
+type MockBody = BoxBody<Bytes, Infallible>;
+
+/// A fault injected into a response of a [`MockWalrusServer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// Delays the response by the given duration, in addition to the server's latency.
+    Latency(Duration),
+    /// Responds with the given status code and a Walrus error body instead of handling the request.
+    Status(reqwest::StatusCode),
+    /// Announces the full `Content-Length` but closes the connection after half of the response body.
+    TruncatedBody,
+}
+
+/// `MockWalrusServerBuilder` configures and starts a [`MockWalrusServer`].
+#[derive(Debug, Clone)]
+pub struct MockWalrusServerBuilder {
+    encoding_config: EncodingConfig,
+    latency: Duration,
+}
+
+impl Default for MockWalrusServerBuilder {
+    fn default() -> Self {
+        Self {
+            encoding_config: EncodingConfig::new(MockWalrusServer::DEFAULT_N_SHARDS)
+                .expect("the default number of shards of the mock server is valid"),
+            latency: Duration::ZERO,
+        }
+    }
+}
+
+impl MockWalrusServerBuilder {
+    /// Sets the encoding configuration used to compute the Blob IDs of stored data.
+    ///
+    /// Defaults to [`MockWalrusServer::DEFAULT_N_SHARDS`] shards, as computing Blob IDs for the 1000 shards of
+    /// Walrus Mainnet and Testnet takes seconds per Blob in unoptimized builds.
+    pub fn encoding_config(mut self, encoding_config: EncodingConfig) -> Self {
+        self.encoding_config = encoding_config;
+        self
+    }
+
+    /// Sets a delay applied to every response. Defaults to none.
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Starts the server on a random local port.
+    ///
+    /// # Returns
+    /// - `Ok(MockWalrusServer)`: The running server.
+    /// - `Err(WalrusError::Io)`: If the port could not be bound or the server thread could not be started.
+    pub fn start(self) -> Result<MockWalrusServer, WalrusError> {
+        let listener = StdTcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let url = format!("http://{}/", listener.local_addr()?);
+        let state = Arc::new(MockState {
+            store: MemoryStore::with_encoding_config(self.encoding_config),
+            network_info: NetworkInfo {
+                current_epoch: MockWalrusServer::CURRENT_EPOCH,
+                current_epoch_start: Some(SystemTime::now()),
+                epoch_duration: MockWalrusServer::EPOCH_DURATION,
+                max_epochs_ahead: MockWalrusServer::MAX_EPOCHS_AHEAD,
+                max_blob_size: MockWalrusServer::MAX_BLOB_SIZE,
+                n_shards: self.encoding_config.n_shards(),
+                prices: Some(MockWalrusServer::PRICES),
+            },
+            faults: Mutex::default(),
+            latency: Mutex::new(self.latency),
+            requests: AtomicUsize::new(0),
+        });
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let (shutdown, shutdown_signal) = oneshot::channel();
+        let server_state = state.clone();
+        let thread = std::thread::Builder::new()
+            .name("walrus-mock-server".to_string())
+            .spawn(move || runtime.block_on(serve(listener, server_state, shutdown_signal)))?;
+
+        Ok(MockWalrusServer {
+            url,
+            encoding_config: self.encoding_config,
+            state,
+            shutdown: Some(shutdown),
+            thread: Some(thread),
+        })
+    }
+}
+
+/// `MockWalrusServer` is an in-process HTTP server implementing the Aggregator and Publisher APIs on top of a
+/// [`MemoryStore`], for testing applications (and this crate) without a network.
+///
+/// It serves `PUT /v1/blobs`, `PUT /v1/quilts` and `GET`/`HEAD` requests for `/v1/blobs/{blob_id}`,
+/// `/v1/blobs/by-object-id/{object_id}`, `/v1/blobs/by-quilt-patch-id/{quilt_patch_id}`,
+/// `/v1/blobs/by-quilt-id/{quilt_id}/{identifier}` (including `Range` requests) and
+/// `/v1/quilts/{quilt_id}/patches`, as well as `GET /v1/api`. Requests are answered like the `MemoryStore` answers the corresponding
+/// [`WalrusStore`] calls; in particular, Blob IDs are computed with the server's [`EncodingConfig`], while object
+/// IDs, costs and transaction digests are made up. Faults such as latency, error statuses and truncated bodies can
+/// be injected with [`MockWalrusServer::inject_fault`].
+///
+/// In addition, the server reports its [`NetworkInfo`] at [`MockWalrusServer::NETWORK_INFO_PATH`], a route that
+/// Walrus services do not have, standing in for the service configured with
+/// [`WalrusClientBuilder::network_info_path`]. Clients created by the server are configured to use it.
+///
+/// The server runs on its own thread, so it can be used from both asynchronous and blocking tests.
+/// It shuts down when dropped.
+///
+/// # Example
+/// ```
+/// use walrus_rs::StoreOptions;
+/// use walrus_rs::testing::MockWalrusServer;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), walrus_rs::WalrusError> {
+/// let server = MockWalrusServer::start()?;
+/// let client = server.client()?;
+///
+/// let result = client.store_blob(b"Hello, Walrus!".to_vec(), &StoreOptions::new()).await?;
+/// let blob_id = result.blob_id().unwrap();
+/// assert_eq!(client.read_blob_by_id(blob_id).await?, b"Hello, Walrus!");
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockWalrusServer {
+    url: String,
+    encoding_config: EncodingConfig,
+    state: Arc<MockState>,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockWalrusServer {
+    /// The default number of shards used to compute Blob IDs.
+    pub const DEFAULT_N_SHARDS: u16 = LOCAL_N_SHARDS;
+
+    /// The epoch reported as the current one in store results.
+    pub const CURRENT_EPOCH: u64 = LOCAL_EPOCH;
+
+    /// The path at which the server reports its [`NetworkInfo`], in the layout printed by `walrus info --json`.
+    pub const NETWORK_INFO_PATH: &'static str = "v1/status";
+
+    /// The duration of an epoch reported at [`MockWalrusServer::NETWORK_INFO_PATH`]. The current epoch starts when the server starts.
+    pub const EPOCH_DURATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// The maximum number of epochs ahead reported at [`MockWalrusServer::NETWORK_INFO_PATH`], as on Walrus Mainnet.
+    pub const MAX_EPOCHS_AHEAD: u64 = 53;
+
+    /// The maximum Blob size reported at [`MockWalrusServer::NETWORK_INFO_PATH`]. It is not enforced.
+    pub const MAX_BLOB_SIZE: u64 = 10 * 1024 * 1024 * 1024;
+
+    /// The storage prices reported at [`MockWalrusServer::NETWORK_INFO_PATH`], which the costs in store results are computed with.
+    pub const PRICES: StoragePrices = LOCAL_PRICES;
+
+    /// Starts a server with default settings on a random local port.
+    ///
+    /// # Returns
+    /// - `Ok(MockWalrusServer)`: The running server.
+    /// - `Err(WalrusError::Io)`: If the port could not be bound or the server thread could not be started.
+    pub fn start() -> Result<Self, WalrusError> {
+        Self::builder().start()
+    }
+
+    /// Creates a [`MockWalrusServerBuilder`] to configure a server.
+    pub fn builder() -> MockWalrusServerBuilder {
+        MockWalrusServerBuilder::default()
+    }
+
+    /// Returns the base URL of the server, which serves as both Aggregator and Publisher.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the encoding configuration used to compute Blob IDs.
+    pub fn encoding_config(&self) -> &EncodingConfig {
+        &self.encoding_config
+    }
+
+    /// Creates a [`WalrusClientBuilder`] using the server as Aggregator and Publisher, with a matching
+    /// encoding configuration so that verified reads succeed, and the server's network info path.
+    pub fn client_builder(&self) -> WalrusClientBuilder {
+        WalrusClient::builder(&self.url, &self.url)
+            .encoding_config(self.encoding_config)
+            .network_info_path(Self::NETWORK_INFO_PATH)
+    }
+
+    /// Creates a [`WalrusClient`] using the server as Aggregator and Publisher.
+    pub fn client(&self) -> Result<WalrusClient, WalrusError> {
+        self.client_builder().build()
+    }
+
+    /// Creates a [`BlockingWalrusClient`] using the server as Aggregator and Publisher.
+    pub fn blocking_client(&self) -> Result<BlockingWalrusClient, WalrusError> {
+        BlockingWalrusClient::builder(&self.url, &self.url)
+            .encoding_config(self.encoding_config)
+            .network_info_path(Self::NETWORK_INFO_PATH)
+            .build()
+    }
+
+    /// Queues a fault, which is applied to the next request that has no fault yet.
+    ///
+    /// Faults are applied in the order they were injected, one per request.
+    pub fn inject_fault(&self, fault: Fault) {
+        self.state.faults.lock().unwrap().push_back(fault);
+    }
+
+    /// Sets the delay applied to every response.
+    pub fn set_latency(&self, latency: Duration) {
+        *self.state.latency.lock().unwrap() = latency;
+    }
+
+    /// Returns the [`NetworkInfo`] reported at [`MockWalrusServer::NETWORK_INFO_PATH`].
+    pub fn network_info(&self) -> &NetworkInfo {
+        &self.state.network_info
+    }
+
+    /// Returns the number of requests received so far.
+    pub fn request_count(&self) -> usize {
+        self.state.requests.load(Ordering::SeqCst)
+    }
+
+    /// Returns the [`MemoryStore`] holding the server's Blobs, e.g. to store Blobs directly, without going through
+    /// the API, or to inspect the stored data.
+    pub fn store(&self) -> &MemoryStore {
+        &self.state.store
+    }
+
+    /// Stores a permanent Blob directly, without going through the API, and returns its Blob ID.
+    pub fn insert_blob(&self, data: impl Into<Vec<u8>>) -> Result<BlobId, WalrusError> {
+        self.state.store.insert_blob(data)
+    }
+
+    /// Returns the data of a stored Blob.
+    pub fn blob(&self, blob_id: &BlobId) -> Option<Vec<u8>> {
+        self.state.store.blob(blob_id)
+    }
+
+    /// Returns the number of distinct Blobs stored, including Quilts.
+    pub fn blob_count(&self) -> usize {
+        self.state.store.blob_count()
+    }
+}
+
+impl Drop for MockWalrusServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl std::fmt::Debug for MockWalrusServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockWalrusServer")
+            .field("url", &self.url)
+            .field("encoding_config", &self.encoding_config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The state shared between a [`MockWalrusServer`] and its server thread.
+struct MockState {
+    store: MemoryStore,
+    network_info: NetworkInfo,
+    faults: Mutex<VecDeque<Fault>>,
+    latency: Mutex<Duration>,
+    requests: AtomicUsize,
+}
+
+/// Accepts connections until the shutdown signal is received.
+async fn serve(
+    listener: StdTcpListener,
+    state: Arc<MockState>,
+    mut shutdown: oneshot::Receiver<()>,
+) {
+    let Ok(listener) = TcpListener::from_std(listener) else {
+        return;
+    };
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => return,
+            accepted = listener.accept() => {
+                let Ok((stream, _)) = accepted else {
+                    continue;
+                };
+                let state = state.clone();
+                tokio::spawn(async move {
+                    let service = service_fn(move |request| handle(state.clone(), request));
+                    let _ = http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+        }
+    }
+}
+
+/// Handles a request, applying the next injected fault.
+async fn handle(
+    state: Arc<MockState>,
+    request: Request<Incoming>,
+) -> Result<Response<MockBody>, Infallible> {
+    state.requests.fetch_add(1, Ordering::SeqCst);
+    let fault = state.faults.lock().unwrap().pop_front();
+    let mut latency = *state.latency.lock().unwrap();
+    if let Some(Fault::Latency(delay)) = fault {
+        latency += delay;
+    }
+    if !latency.is_zero() {
+        tokio::time::sleep(latency).await;
+    }
+
+    let response = match fault {
+        Some(Fault::Status(status)) => Rejection::new(
+            status,
+            "INJECTED_FAULT",
+            "the mock server was asked to fail this request",
+        )
+        .into_response(),
+        _ => route(&state, request)
+            .await
+            .unwrap_or_else(Rejection::into_response),
+    };
+    Ok(match fault {
+        Some(Fault::TruncatedBody) => truncated(response).await,
+        _ => response.map(|body| body.boxed()),
+    })
+}
+
+/// Dispatches a request to the handler of its endpoint.
+async fn route(
+    state: &MockState,
+    request: Request<Incoming>,
+) -> Result<Response<Full<Bytes>>, Rejection> {
+    let path = request.uri().path().to_owned();
+    let query: HashMap<String, String> = request
+        .uri()
+        .query()
+        .map(|query| {
+            url::form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect()
+        })
+        .unwrap_or_default();
+    let segments: Vec<String> = path
+        .trim_start_matches('/')
+        .split('/')
+        .map(|segment| percent_decode_str(segment).decode_utf8_lossy().into_owned())
+        .collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+    let (parts, body) = request.into_parts();
+    let is_read = parts.method == Method::GET || parts.method == Method::HEAD;
+
+    match segments.as_slice() {
+        ["v1", "blobs"] if parts.method == Method::PUT => {
+            store_blob(state, &query, read_body(body).await?).await
+        }
+        ["v1", "quilts"] if parts.method == Method::PUT => {
+            let content_type = parts
+                .headers
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default()
+                .to_owned();
+            store_quilt(state, &query, &content_type, &read_body(body).await?).await
+        }
+        ["v1", "blobs", "by-object-id", object_id] if is_read => {
+            let object_id = parse_id(object_id)?;
+            let data = state.store.read_blob_by_object_id(&object_id).await?;
+            Ok(data_response(&parts.headers, data.into()))
+        }
+        ["v1", "blobs", "by-quilt-patch-id", patch_id] if is_read => {
+            let patch_id = parse_id(patch_id)?;
+            let patch = state.store.read_quilt_patch_by_patch_id(&patch_id).await?;
+            Ok(patch_response(&parts.headers, patch))
+        }
+        ["v1", "blobs", "by-quilt-id", quilt_id, identifier] if is_read => {
+            let quilt_id = parse_id(quilt_id)?;
+            let patch = state
+                .store
+                .read_quilt_patch_by_quilt_id_and_identifier(&quilt_id, identifier)
+                .await?;
+            Ok(patch_response(&parts.headers, patch))
+        }
+        ["v1", "quilts", quilt_id, "patches"] if is_read => {
+            let quilt_id = parse_id(quilt_id)?;
+            Ok(json_response(
+                &state.store.list_quilt_patches(&quilt_id).await?,
+            ))
+        }
+        _ if is_read && path[1..] == *MockWalrusServer::NETWORK_INFO_PATH => {
+            Ok(json_response(&state.network_info))
+        }
+        ["v1", "api"] if is_read => Ok(json_response(&api_spec())),
+        ["v1", "blobs", blob_id] if is_read => {
+            let blob_id = parse_id(blob_id)?;
+            let data = state.store.read_blob_by_id(&blob_id).await?;
+            Ok(data_response(&parts.headers, data.into()))
+        }
+        _ => Err(Rejection::new(
+            StatusCode::NOT_FOUND,
+            "UNKNOWN_ENDPOINT",
+            format!("{} {path} is not served by the mock server", parts.method),
+        )),
+    }
+}
+
+/// Returns a minimal OpenAPI document listing the endpoints served by the mock server.
+fn api_spec() -> serde_json::Value {
+    let paths = [
+        "/v1/api",
+        "/v1/blobs",
+        "/v1/blobs/{blob_id}",
+        "/v1/blobs/by-object-id/{blob_object_id}",
+        "/v1/blobs/by-quilt-id/{quilt_id}/{identifier}",
+        "/v1/blobs/by-quilt-patch-id/{quilt_patch_id}",
+        "/v1/quilts",
+        "/v1/quilts/{quilt_id}/patches",
+    ];
+    serde_json::json!({
+        "openapi": "3.0.3",
+        "info": {"title": "Walrus mock server", "version": env!("CARGO_PKG_VERSION")},
+        "paths": paths
+            .iter()
+            .map(|path| (path.to_string(), serde_json::json!({})))
+            .collect::<serde_json::Map<_, _>>(),
+    })
+}
+
+/// Reads the whole body of a request.
+async fn read_body(body: Incoming) -> Result<Bytes, Rejection> {
+    body.collect()
+        .await
+        .map(|body| body.to_bytes())
+        .map_err(|e| Rejection::bad_request(format!("failed to read the request body: {e}")))
+}
+
+/// Handles `PUT /v1/blobs`.
+async fn store_blob(
+    state: &MockState,
+    query: &HashMap<String, String>,
+    data: Bytes,
+) -> Result<Response<Full<Bytes>>, Rejection> {
+    let options = store_options(query)?;
+    let result = state.store.store_blob(data.into(), &options).await?;
+    Ok(json_response(&result))
+}
+
+/// Handles `PUT /v1/quilts`, storing the files and the `_metadata` part of a multipart form as a Quilt.
+///
+/// Quilts are stored in the format of [`MemoryStore`], which differs from the encoding of real Quilts.
+async fn store_quilt(
+    state: &MockState,
+    query: &HashMap<String, String>,
+    content_type: &str,
+    body: &[u8],
+) -> Result<Response<Full<Bytes>>, Rejection> {
+    let options = store_options(query)?;
+    let parts = parse_multipart(content_type, body)
+        .ok_or_else(|| Rejection::bad_request("the request body is not a valid multipart form"))?;
+    let (metadata, files): (Vec<_>, Vec<_>) =
+        parts.into_iter().partition(|(name, _)| name == "_metadata");
+    let metadata = match metadata.first() {
+        Some((_, metadata)) => Some(
+            serde_json::from_slice::<Vec<QuiltMetadata>>(metadata)
+                .map_err(|e| Rejection::bad_request(format!("invalid Quilt metadata: {e}")))?,
+        ),
+        None => None,
+    };
+    let files = files
+        .iter()
+        .map(|(identifier, data)| (identifier.as_str(), data.to_vec()))
+        .collect();
+    let response = state.store.store_quilt(files, metadata, &options).await?;
+    Ok(json_response(&response))
+}
+
+/// Parses the [`StoreOptions`] of a store request from its query.
+fn store_options(query: &HashMap<String, String>) -> Result<StoreOptions, Rejection> {
+    let invalid = |name: &str, value: &str| {
+        Rejection::bad_request(format!("invalid value {value:?} for {name}"))
+    };
+    let mut options = StoreOptions::new();
+    for (name, value) in query {
+        options = match name.as_str() {
+            "epochs" => options.epochs(value.parse().map_err(|_| invalid(name, value))?),
+            "deletable" => options.deletable(value.parse().map_err(|_| invalid(name, value))?),
+            "permanent" => options.permanent(value.parse().map_err(|_| invalid(name, value))?),
+            "send_object_to" => {
+                options.send_object_to(value.parse().map_err(|_| invalid(name, value))?)
+            }
+            _ => options,
+        };
+    }
+    Ok(options)
+}
+
+/// Parses an ID from a path segment.
+fn parse_id<T: std::str::FromStr>(id: &str) -> Result<T, Rejection> {
+    id.parse()
+        .map_err(|_| Rejection::bad_request(format!("invalid ID {id:?}")))
+}
+
+/// Responds with the data of a Blob or Quilt patch, or the part selected by the request's `Range` header.
+fn data_response(headers: &HeaderMap, data: Bytes) -> Response<Full<Bytes>> {
+    let response = Response::builder()
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .header(ETAG, format!("\"{}\"", hash_hex(&data)));
+    let Some(range) = headers.get(RANGE).and_then(|value| value.to_str().ok()) else {
+        return response.body(Full::new(data)).unwrap();
+    };
+
+    let total = data.len();
+    match parse_range(range, total) {
+        Some((start, end)) => response
+            .status(StatusCode::PARTIAL_CONTENT)
+            .header(CONTENT_RANGE, format!("bytes {start}-{}/{total}", end - 1))
+            .body(Full::new(data.slice(start..end)))
+            .unwrap(),
+        None => Response::builder()
+            .status(StatusCode::RANGE_NOT_SATISFIABLE)
+            .header(CONTENT_RANGE, format!("bytes */{total}"))
+            .body(Full::default())
+            .unwrap(),
+    }
+}
+
+/// Responds with the data of a Quilt patch like [`data_response`], adding its identifier and tags as headers.
+fn patch_response(headers: &HeaderMap, patch: QuiltPatchResponse) -> Response<Full<Bytes>> {
+    let mut response = data_response(headers, patch.data.into());
+    for (name, value) in &patch.headers {
+        if name != CONTENT_LENGTH {
+            response.headers_mut().insert(name, value.clone());
+        }
+    }
+    response
+}
+
+/// Parses a single-range `Range` header value into the start and (exclusive) end of the selected bytes.
+fn parse_range(range: &str, total: usize) -> Option<(usize, usize)> {
+    let (start, end) = range.strip_prefix("bytes=")?.split_once('-')?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", suffix) => {
+            let suffix: usize = suffix.parse().ok()?;
+            (total.saturating_sub(suffix), total)
+        }
+        (start, "") => (start.parse().ok()?, total),
+        (start, end) => {
+            let end: usize = end.parse().ok()?;
+            (start.parse().ok()?, total.min(end.checked_add(1)?))
+        }
+    };
+    (start < end).then_some((start, end))
+}
+
+/// Parses the parts of a `multipart/form-data` body into their names and contents.
+fn parse_multipart(content_type: &str, body: &[u8]) -> Option<Vec<(String, Bytes)>> {
+    let boundary = content_type
+        .split(';')
+        .find_map(|param| param.trim().strip_prefix("boundary="))?
+        .trim_matches('"');
+    let delimiter = format!("\r\n--{boundary}");
+    // Prefixing the body with a line break lets the first delimiter be found like the others.
+    let body = [b"\r\n".as_slice(), body].concat();
+    let mut rest = &body[find(&body, delimiter.as_bytes())? + delimiter.len()..];
+
+    let mut parts = Vec::new();
+    while !rest.starts_with(b"--") {
+        let end = find(rest, delimiter.as_bytes())?;
+        let part = &rest[..end];
+        let header_end = find(part, b"\r\n\r\n")?;
+        let headers = std::str::from_utf8(&part[..header_end]).ok()?;
+        let name = headers.lines().find_map(|line| {
+            let (header, value) = line.split_once(':')?;
+            if !header.trim().eq_ignore_ascii_case("content-disposition") {
+                return None;
+            }
+            // Names with special characters are sent percent-encoded in the extended `name*=` notation.
+            let name = match value.split_once("name*=utf-8''") {
+                Some((_, encoded)) => encoded.split(';').next()?.trim(),
+                None => value.split_once("name=\"")?.1.split_once('"')?.0,
+            };
+            Some(percent_decode_str(name).decode_utf8_lossy().into_owned())
+        })?;
+        parts.push((name, Bytes::copy_from_slice(&part[header_end + 4..])));
+        rest = &rest[end + delimiter.len()..];
+    }
+    Some(parts)
+}
+
+/// Returns the position of the first occurrence of `needle` in `haystack`.
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn json_response(value: &impl serde::Serialize) -> Response<Full<Bytes>> {
+    Response::builder()
+        .header(CONTENT_TYPE, "application/json")
+        .body(Full::new(
+            serde_json::to_vec(value)
+                .expect("API models are always serializable")
+                .into(),
+        ))
+        .unwrap()
+}
+
+/// A request rejected with an error status, answered with an error body in the format of the Walrus API.
+struct Rejection {
+    status: StatusCode,
+    reason: String,
+    message: String,
+}
+
+impl Rejection {
+    fn new(status: StatusCode, reason: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            reason: reason.into(),
+            message: message.into(),
+        }
+    }
+
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "INVALID_REQUEST", message)
+    }
+
+    fn into_response(self) -> Response<Full<Bytes>> {
+        let body = serde_json::json!({
+            "error": {
+                "code": self.status.as_u16(),
+                "status": self.status
+                    .canonical_reason()
+                    .unwrap_or("UNKNOWN")
+                    .to_uppercase()
+                    .replace(' ', "_"),
+                "message": self.message,
+                "details": [{
+                    "@type": "ErrorInfo",
+                    "reason": self.reason,
+                    "domain": "mock.walrus.space",
+                    "metadata": {},
+                }],
+            }
+        });
+        let mut response = json_response(&body);
+        *response.status_mut() = self.status;
+        response
+    }
+}
+
+impl From<WalrusError> for Rejection {
+    /// Answers errors of the [`MemoryStore`] backing the server: API errors keep their status and reason.
+    fn from(error: WalrusError) -> Self {
+        match error {
+            WalrusError::ApiError(e) => {
+                let reason = e.reason().unwrap_or("UNKNOWN").to_string();
+                Self::new(e.status(), reason, e.message())
+            }
+            WalrusError::InvalidParameter(message) => Self::bad_request(message),
+            e => Self::new(StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL", e.to_string()),
+        }
+    }
+}
+
+/// Cuts a response off halfway through its body, while still announcing the full length.
+async fn truncated(response: Response<Full<Bytes>>) -> Response<MockBody> {
+    let (mut parts, body) = response.into_parts();
+    let data = match body.collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(never) => match never {},
+    };
+    parts.headers.insert(CONTENT_LENGTH, data.len().into());
+    // A streamed body has no known size, so the announced length is not corrected to the truncated one.
+    // Yielding once after the first half lets it be flushed before the connection is closed.
+    let half = data.slice(..data.len() / 2);
+    let chunks = futures_util::stream::iter([Ok(Frame::data(half))]).chain(
+        futures_util::stream::once(tokio::task::yield_now()).filter_map(|()| async { None }),
+    );
+    let body = StreamBody::new(chunks);
+    Response::from_parts(parts, BodyExt::boxed(body))
+}
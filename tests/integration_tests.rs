@@ -1,7 +1,108 @@
 #[cfg(test)]
 mod tests {
-    use tokio;
-    use walrus_rs::{BlockingWalrusClient, WalrusClient, WalrusError};
+    use reqwest::header::{HeaderName, HeaderValue};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use walrus_rs::models::{BlobStoreResult, QuiltMetadata};
+    use walrus_rs::testing::{Fault, MockWalrusServer};
+    use walrus_rs::{
+        ApiError, BlobId, BlobStream, BlockingWalrusClient, ByteRange, ChunkManifest,
+        ChunkedUploadOptions, EncodingConfig, FileSystemStore, MemoryStore, NetworkInfo, ObjectId,
+        Progress, QuiltDirOptions, QuiltPatchId, RequestKind, RetryPolicy, SelectionStrategy,
+        StoragePrices, StoreCostEstimate, StoreOptions, SuiAddress, TransferDirection,
+        WalrusClient, WalrusError, WalrusStore,
+    };
+
+    /// Starts a local HTTP server that answers each connection with the next canned response
+    /// (repeating the last one) and returns its URL together with a counter of served requests.
+    async fn serve_responses(responses: Vec<&'static str>) -> (String, Arc<AtomicUsize>) {
+        let (url, served, _) = serve_recording(responses).await;
+        (url, served)
+    }
+
+    /// Like [`serve_responses`], but additionally records the raw requests received.
+    async fn serve_recording(
+        responses: Vec<&'static str>,
+    ) -> (String, Arc<AtomicUsize>, Arc<Mutex<Vec<Vec<u8>>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let served = Arc::new(AtomicUsize::new(0));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let counter = served.clone();
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let index = counter.fetch_add(1, Ordering::SeqCst);
+                let response = responses[index.min(responses.len() - 1)];
+                let request = read_request(&mut stream).await;
+                recorded.lock().unwrap().push(request);
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+        (url, served, requests)
+    }
+
+    /// Reads an HTTP/1.1 request with a `Content-Length` body from the stream.
+    async fn read_request(stream: &mut tokio::net::TcpStream) -> Vec<u8> {
+        let mut request = Vec::new();
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            let Ok(n) = stream.read(&mut buf).await else {
+                return request;
+            };
+            if n == 0 {
+                return request;
+            }
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let content_length = text[..header_end]
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length")
+                            .then(|| value.trim().parse::<usize>().ok())?
+                    })
+                    .unwrap_or(0);
+                if request.len() >= header_end + 4 + content_length {
+                    return request;
+                }
+            }
+        }
+    }
+
+    const UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const NOT_FOUND: &str =
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const BLOB_NOT_FOUND: &str = "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 194\r\nConnection: close\r\n\r\n{\"error\":{\"code\":404,\"status\":\"NOT_FOUND\",\"message\":\"the requested blob does not exist\",\"details\":[{\"@type\":\"ErrorInfo\",\"reason\":\"BLOB_NOT_FOUND\",\"domain\":\"daemon.walrus.space\",\"metadata\":{}}]}}";
+    const OK_HELLO: &str = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello";
+
+    const PARTIAL_ELL: &str = "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 1-3/5\r\nContent-Length: 3\r\nConnection: close\r\n\r\nell";
+
+    const STORED: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 130\r\nConnection: close\r\n\r\n{\"alreadyCertified\":{\"blobId\":\"jUtX26C8c9csndZOUSrYmyLKlL_4CPfH1M4fnTI_kjY\",\"event\":{\"txDigest\":\"d\",\"eventSeq\":\"0\"},\"endEpoch\":5}}";
+
+    fn blob_id() -> BlobId {
+        "jUtX26C8c9csndZOUSrYmyLKlL_4CPfH1M4fnTI_kjY"
+            .parse()
+            .unwrap()
+    }
+
+    fn patch_id() -> QuiltPatchId {
+        QuiltPatchId::new(blob_id(), vec![1, 0, 0, 1, 0]).unwrap()
+    }
+
+    fn fast_retries(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new()
+            .max_attempts(max_attempts)
+            .initial_backoff(Duration::from_millis(1))
+            .max_backoff(Duration::from_millis(5))
+    }
 
     #[tokio::test]
     async fn test_walrus_client_new() {
@@ -31,16 +132,965 @@ mod tests {
             panic!("Expected InvalidUrl error");
         }
     }
+    #[tokio::test]
+    async fn test_walrus_client_builder() {
+        let aggregator_url = "https://aggregator.testnet.walrus.atalma.io/";
+        let publisher_url = "https://publisher.walrus-01.tududes.com/";
+        let client = WalrusClient::builder(aggregator_url, publisher_url)
+            .connect_timeout(Duration::from_secs(5))
+            .timeout(Duration::from_secs(30))
+            .read_timeout(Duration::from_secs(10))
+            .user_agent("walrus-rs-test")
+            .default_header(
+                HeaderName::from_static("x-api-key"),
+                HeaderValue::from_static("secret"),
+            )
+            .no_proxy()
+            .http1_only()
+            .build()
+            .unwrap();
+
+        assert_eq!(client.aggregator_url().as_str(), aggregator_url);
+        assert_eq!(client.publisher_url().as_str(), publisher_url);
+    }
+
+    #[tokio::test]
+    async fn test_walrus_client_builder_invalid_publisher_url() {
+        let client_result =
+            WalrusClient::builder("https://aggregator.testnet.walrus.atalma.io", "invalid-url")
+                .http_client(reqwest::Client::new())
+                .build();
+        if let Err(WalrusError::InvalidUrl(msg)) = client_result {
+            assert!(msg.contains("Invalid publisher URL"));
+        } else {
+            panic!("Expected InvalidUrl error");
+        }
+    }
+
+    #[test]
+    fn test_blocking_walrus_client_builder() {
+        let aggregator_url = "https://aggregator.testnet.walrus.atalma.io";
+        let publisher_url = "https://publisher.walrus-01.tududes.com";
+        let client_result = BlockingWalrusClient::builder(aggregator_url, publisher_url)
+            .timeout(Duration::from_secs(30))
+            .build();
+        assert!(client_result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_read_retries_transient_errors() {
+        let (url, served) = serve_responses(vec![UNAVAILABLE, UNAVAILABLE, OK_HELLO]).await;
+        let client = WalrusClient::builder(&url, &url)
+            .retry_policy(fast_retries(3))
+            .build()
+            .unwrap();
+
+        let data = client.read_blob_by_id(&blob_id()).await.unwrap();
+        assert_eq!(data, b"hello");
+        assert_eq!(served.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_read_gives_up_after_max_attempts() {
+        let (url, served) = serve_responses(vec![UNAVAILABLE]).await;
+        let client = WalrusClient::builder(&url, &url)
+            .retry_policy(fast_retries(2))
+            .build()
+            .unwrap();
+
+        let error = client.read_blob_by_id(&blob_id()).await.unwrap_err();
+        assert!(error.api_error().is_some_and(|e| e.is_retryable()));
+        assert_eq!(served.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_status_is_not_retried() {
+        let (url, served) = serve_responses(vec![NOT_FOUND, OK_HELLO]).await;
+        let client = WalrusClient::builder(&url, &url)
+            .retry_policy(fast_retries(3))
+            .build()
+            .unwrap();
+
+        assert!(client.read_blob_by_id(&blob_id()).await.is_err());
+        assert_eq!(served.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_api_error_is_decoded() {
+        let (url, _) = serve_responses(vec![BLOB_NOT_FOUND]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+
+        let error = client.read_blob_by_id(&blob_id()).await.unwrap_err();
+        let Some(api_error) = error.api_error() else {
+            panic!("Expected ApiError, got {error:?}");
+        };
+        assert!(api_error.is_not_found());
+        assert!(!api_error.is_retryable());
+        assert_eq!(api_error.kind(), RequestKind::ReadBlob);
+        assert_eq!(api_error.url().path(), format!("/v1/blobs/{}", blob_id()));
+        assert_eq!(api_error.code(), Some("NOT_FOUND"));
+        assert_eq!(api_error.reason(), Some("BLOB_NOT_FOUND"));
+        assert_eq!(api_error.message(), "the requested blob does not exist");
+        assert_eq!(
+            api_error.details()[0].domain.as_deref(),
+            Some("daemon.walrus.space")
+        );
+    }
+
+    #[test]
+    fn test_api_error_predicates() {
+        let url: reqwest::Url = "http://localhost/v1/blobs".parse().unwrap();
+        let error = ApiError::new(
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            RequestKind::StoreBlob,
+            url.clone(),
+            r#"{"error":{"code":500,"status":"INTERNAL","message":"could not find WAL coins with sufficient balance"}}"#,
+        );
+        assert!(error.is_insufficient_funds());
+        assert!(!error.is_retryable());
+        assert_eq!(error.reason(), None);
+
+        for (message, insufficient_funds) in [
+            ("Insufficient funds for gas", true),
+            ("InsufficientCoinBalance in command 0", true),
+            ("insufficient storage nodes to store the blob", false),
+            ("insufficient shards responded", false),
+        ] {
+            let error = ApiError::new(
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                RequestKind::StoreBlob,
+                url.clone(),
+                message,
+            );
+            assert_eq!(
+                error.is_insufficient_funds(),
+                insufficient_funds,
+                "{message}"
+            );
+        }
+
+        let error = ApiError::new(
+            reqwest::StatusCode::BAD_GATEWAY,
+            RequestKind::StoreBlob,
+            url,
+            "<html>bad gateway</html>\n",
+        );
+        assert!(error.is_retryable());
+        assert!(!error.is_insufficient_funds());
+        assert!(error.body().is_none());
+        assert_eq!(error.message(), "<html>bad gateway</html>");
+    }
+
+    #[tokio::test]
+    async fn test_deletable_store_is_not_retried() {
+        let (url, served) = serve_responses(vec![UNAVAILABLE]).await;
+        let client = WalrusClient::builder(&url, &url)
+            .retry_policy(fast_retries(3))
+            .build()
+            .unwrap();
+
+        let result = client
+            .store_blob(b"data".to_vec(), &StoreOptions::new().deletable(true))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(served.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_only_permanent_stores_are_retried() {
+        let cases = [
+            (StoreOptions::new().permanent(true), 2),
+            (StoreOptions::new().deletable(false), 2),
+            (StoreOptions::new().epochs(1), 1),
+        ];
+        for (options, expected_attempts) in cases {
+            let (url, served) = serve_responses(vec![UNAVAILABLE, STORED]).await;
+            let client = WalrusClient::builder(&url, &url)
+                .retry_policy(fast_retries(3))
+                .build()
+                .unwrap();
+
+            let result = client.store_blob(b"data".to_vec(), &options).await;
+            assert_eq!(result.is_ok(), expected_attempts == 2, "{options:?}");
+            assert_eq!(
+                served.load(Ordering::SeqCst),
+                expected_attempts,
+                "{options:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_permanent_quilt_store_is_retried() {
+        let (url, served) = serve_responses(vec![UNAVAILABLE, OK_HELLO]).await;
+        let client = WalrusClient::builder(&url, &url)
+            .retry_policy(fast_retries(3))
+            .build()
+            .unwrap();
+
+        let files = vec![("file1.txt", b"content".to_vec())];
+        let result = client
+            .store_quilt(files, None, &StoreOptions::new().permanent(true))
+            .await;
+        // The second attempt reaches the server, whose body is not a valid store response.
+        assert!(matches!(result, Err(WalrusError::ParseError(_))));
+        assert_eq!(served.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_fails_over_to_next_aggregator() {
+        let (down, down_served) = serve_responses(vec![UNAVAILABLE]).await;
+        let (up, up_served) = serve_responses(vec![OK_HELLO]).await;
+        let client = WalrusClient::builder(&down, &down)
+            .add_aggregator_url(&up)
+            .selection_strategy(SelectionStrategy::Priority)
+            .failure_threshold(1)
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        assert_eq!(client.read_blob_by_id(&blob_id()).await.unwrap(), b"hello");
+        let status = client.aggregators().status();
+        assert!(!status[0].healthy);
+        assert!(status[1].healthy);
+
+        // The unhealthy endpoint is skipped while it cools down.
+        assert_eq!(client.read_blob_by_id(&blob_id()).await.unwrap(), b"hello");
+        assert_eq!(down_served.load(Ordering::SeqCst), 1);
+        assert_eq!(up_served.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_fails_over_on_not_found() {
+        let (lagging, lagging_served) = serve_responses(vec![BLOB_NOT_FOUND, NOT_FOUND]).await;
+        let (up, up_served) = serve_responses(vec![OK_HELLO, NOT_FOUND]).await;
+        let client = WalrusClient::builder(&lagging, &lagging)
+            .add_aggregator_url(&up)
+            .selection_strategy(SelectionStrategy::Priority)
+            .failure_threshold(1)
+            .retry_policy(fast_retries(3))
+            .build()
+            .unwrap();
+
+        // An Aggregator missing the Blob is not marked unhealthy.
+        assert_eq!(client.read_blob_by_id(&blob_id()).await.unwrap(), b"hello");
+        assert!(client.aggregators().status()[0].healthy);
+
+        // A Blob missing on every Aggregator fails without another round.
+        let Err(WalrusError::ApiError(error)) = client.read_blob_by_id(&blob_id()).await else {
+            panic!("Expected ApiError");
+        };
+        assert!(error.is_not_found());
+        assert_eq!(lagging_served.load(Ordering::SeqCst), 2);
+        assert_eq!(up_served.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cloned_clients_share_endpoint_health() {
+        let (down, down_served) = serve_responses(vec![UNAVAILABLE]).await;
+        let (up, _) = serve_responses(vec![OK_HELLO]).await;
+        let client = WalrusClient::builder(&down, &down)
+            .add_aggregator_url(&up)
+            .selection_strategy(SelectionStrategy::Priority)
+            .failure_threshold(1)
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        let clone = client.clone();
+        let data = tokio::spawn(async move { clone.read_blob_by_id(&blob_id()).await })
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(data, b"hello");
+        assert!(!client.aggregators().status()[0].healthy);
+
+        // The endpoint marked unhealthy by the clone is skipped by the original client.
+        assert_eq!(client.read_blob_by_id(&blob_id()).await.unwrap(), b"hello");
+        assert_eq!(down_served.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unhealthy_endpoint_is_retried_after_cooldown() {
+        let (flaky, flaky_served) = serve_responses(vec![UNAVAILABLE, OK_HELLO]).await;
+        let (up, _) = serve_responses(vec![OK_HELLO]).await;
+        let client = WalrusClient::builder(&flaky, &flaky)
+            .add_aggregator_url(&up)
+            .selection_strategy(SelectionStrategy::Priority)
+            .failure_threshold(1)
+            .unhealthy_cooldown(Duration::from_millis(20))
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        client.read_blob_by_id(&blob_id()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        client.read_blob_by_id(&blob_id()).await.unwrap();
+        assert_eq!(flaky_served.load(Ordering::SeqCst), 2);
+        assert!(client.aggregators().status()[0].healthy);
+    }
+
+    #[tokio::test]
+    async fn test_round_robin_spreads_requests() {
+        let (first, first_served) = serve_responses(vec![OK_HELLO]).await;
+        let (second, second_served) = serve_responses(vec![OK_HELLO]).await;
+        let client = WalrusClient::builder(&first, &first)
+            .add_aggregator_url(&second)
+            .build()
+            .unwrap();
+
+        for _ in 0..4 {
+            client.read_blob_by_id(&blob_id()).await.unwrap();
+        }
+        assert_eq!(first_served.load(Ordering::SeqCst), 2);
+        assert_eq!(second_served.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_blob_stream() {
+        let (url, _) = serve_responses(vec![OK_HELLO]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+
+        let stream = client.read_blob_stream_by_id(&blob_id()).await.unwrap();
+        assert_eq!(stream.content_length(), Some(5));
+        assert_eq!(stream.collect_bytes().await.unwrap(), b"hello");
+
+        // An announced length is not trusted to allocate memory up front.
+        let stream = BlobStream::new(
+            futures_util::stream::iter([Ok(bytes::Bytes::from_static(b"hello"))]),
+            Some(u64::MAX),
+        );
+        assert_eq!(stream.collect_bytes().await.unwrap(), b"hello");
+
+        let mut reader = client
+            .read_quilt_blob_stream_by_patch_id(&patch_id())
+            .await
+            .unwrap()
+            .into_async_read();
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).await.unwrap();
+        assert_eq!(data, b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_read_blob_range_partial_content() {
+        let (url, _, requests) = serve_recording(vec![PARTIAL_ELL]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+
+        let data = client.read_blob_range(&blob_id(), 1..4).await.unwrap();
+        assert_eq!(data, b"ell");
+        let request = String::from_utf8(requests.lock().unwrap()[0].clone()).unwrap();
+        assert!(request.contains("range: bytes=1-3\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_read_blob_range_checks_content_range() {
+        let (url, _) = serve_responses(vec![
+            "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 0-4/5\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello",
+            "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 2-4/5\r\nContent-Length: 3\r\nConnection: close\r\n\r\nllo",
+            "HTTP/1.1 206 Partial Content\r\nContent-Length: 3\r\nConnection: close\r\n\r\nell",
+        ])
+        .await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+        let id = blob_id();
+
+        // A range covering the requested one is cut to it.
+        assert_eq!(client.read_blob_range(&id, 1..4).await.unwrap(), b"ell");
+        // A shifted range and a missing Content-Range are rejected.
+        assert!(matches!(
+            client.read_blob_range(&id, 1..4).await,
+            Err(WalrusError::ParseError(_))
+        ));
+        assert!(matches!(
+            client.read_blob_range(&id, 1..4).await,
+            Err(WalrusError::ParseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_blob_range_ignored_by_server() {
+        let (url, _) = serve_responses(vec![OK_HELLO]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+        let id = blob_id();
+
+        assert_eq!(client.read_blob_range(&id, 1..4).await.unwrap(), b"ell");
+        assert_eq!(client.read_blob_range(&id, 3..).await.unwrap(), b"lo");
+        assert_eq!(client.read_blob_range(&id, ..=0).await.unwrap(), b"h");
+        assert_eq!(client.read_blob_range(&id, 2..100).await.unwrap(), b"llo");
+        assert_eq!(
+            client
+                .read_quilt_blob_range_by_patch_id(&patch_id(), ByteRange::suffix(2))
+                .await
+                .unwrap(),
+            b"lo"
+        );
+        assert!(matches!(
+            client.read_blob_range(&id, 2..2).await,
+            Err(WalrusError::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_progress_observer() {
+        let (url, _) = serve_responses(vec![STORED, OK_HELLO]).await;
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let recorded = updates.clone();
+        let client = WalrusClient::builder(&url, &url)
+            .progress_observer(move |progress: &Progress| recorded.lock().unwrap().push(*progress))
+            .build()
+            .unwrap();
+
+        client
+            .store_blob(vec![7u8; 100_000], &StoreOptions::new())
+            .await
+            .unwrap();
+        let uploads = std::mem::take(&mut *updates.lock().unwrap());
+        assert!(uploads.len() > 2);
+        assert!(uploads.iter().all(|p| p.kind == RequestKind::StoreBlob
+            && p.direction == TransferDirection::Upload
+            && p.total == Some(100_000)));
+        assert_eq!(uploads[0].transferred, 0);
+        assert_eq!(uploads.last().unwrap().fraction(), Some(1.0));
+
+        assert_eq!(client.read_blob_by_id(&blob_id()).await.unwrap(), b"hello");
+        let downloads = updates.lock().unwrap();
+        let last = downloads.last().unwrap();
+        assert_eq!(last.direction, TransferDirection::Download);
+        assert_eq!((last.transferred, last.total), (5, Some(5)));
+    }
+
+    #[test]
+    fn test_blocking_blob_reader() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (url, _) = runtime.block_on(serve_responses(vec![OK_HELLO]));
+        let client = BlockingWalrusClient::new(&url, &url).unwrap();
+
+        let mut reader = client.read_blob_reader_by_id(&blob_id()).unwrap();
+        let mut data = String::new();
+        std::io::Read::read_to_string(&mut reader, &mut data).unwrap();
+        assert_eq!(data, "hello");
+    }
+
+    #[test]
+    fn test_blocking_client_shared_between_threads() {
+        fn assert_send_sync_clone<T: Send + Sync + Clone>() {}
+        assert_send_sync_clone::<BlockingWalrusClient>();
+
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.blocking_client().unwrap();
+        let handles: Vec<_> = (0..4u8)
+            .map(|i| {
+                let client = client.clone();
+                std::thread::spawn(move || {
+                    let result = client
+                        .store_blob(vec![i; 100], &StoreOptions::new())
+                        .unwrap();
+                    client.read_blob_by_id(result.blob_id().unwrap()).unwrap()
+                })
+            })
+            .collect();
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.join().unwrap(), vec![i as u8; 100]);
+        }
+
+        // Readers are not tied to the client they were opened with.
+        let blob_id = server.insert_blob(&b"hello"[..]).unwrap();
+        let mut reader = client.read_blob_reader_by_id(&blob_id).unwrap();
+        drop(client);
+        let mut data = String::new();
+        std::io::Read::read_to_string(&mut reader, &mut data).unwrap();
+        assert_eq!(data, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_blocking_client_in_async_context() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.blocking_client().unwrap();
+        let blob_id = server.insert_blob(&b"hello"[..]).unwrap();
+
+        // The client neither panics nor relies on catching panics inside a runtime.
+        let test_thread = std::thread::current().id();
+        let panics = Arc::new(AtomicUsize::new(0));
+        let hook_panics = panics.clone();
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |_| {
+            if std::thread::current().id() == test_thread {
+                hook_panics.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+        let data = client.read_blob_by_id(&blob_id);
+        let mut streamed = Vec::new();
+        let streamed_length = client
+            .read_blob_reader_by_id(&blob_id)
+            .and_then(|mut reader| Ok(std::io::Read::read_to_end(&mut reader, &mut streamed)?));
+        std::panic::set_hook(previous_hook);
+
+        assert_eq!(panics.load(Ordering::SeqCst), 0);
+        assert_eq!(data.unwrap(), b"hello");
+        assert_eq!(streamed_length.unwrap(), 5);
+        assert_eq!(streamed, b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_blocking_client_in_spawn_blocking() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.blocking_client().unwrap();
+        let blob_id = server.insert_blob(&b"hello"[..]).unwrap();
+
+        let data = tokio::task::spawn_blocking(move || client.read_blob_by_id(&blob_id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(data, b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_store_blob_from_path() {
+        let (url, _, requests) = serve_recording(vec![STORED]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+        let path = std::env::temp_dir().join(format!("walrus-rs-test-{}", std::process::id()));
+        tokio::fs::write(&path, b"file content").await.unwrap();
+
+        let result = client
+            .store_blob_from_path(&path, &StoreOptions::new().epochs(1))
+            .await;
+        tokio::fs::remove_file(&path).await.unwrap();
+        assert!(matches!(
+            result.unwrap(),
+            BlobStoreResult::AlreadyCertified(_)
+        ));
+
+        let request = String::from_utf8(requests.lock().unwrap()[0].clone()).unwrap();
+        assert!(request.starts_with("PUT /v1/blobs?epochs=1 "));
+        assert!(request.to_lowercase().contains("content-length: 12"));
+        assert!(request.ends_with("file content"));
+
+        let missing = client
+            .store_blob_from_path(&path, &StoreOptions::new().epochs(1))
+            .await;
+        assert!(matches!(missing, Err(WalrusError::Io(_))));
+    }
+
+    #[tokio::test]
+    async fn test_store_file_chunked() {
+        let (url, _, requests) = serve_recording(vec![STORED]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+        let path = std::env::temp_dir().join(format!("walrus-rs-chunked-{}", std::process::id()));
+        tokio::fs::write(&path, b"0123456789").await.unwrap();
+
+        let result = client
+            .store_file_chunked(
+                &path,
+                &ChunkedUploadOptions::new().chunk_size(4).concurrency(1),
+                &StoreOptions::new().epochs(1),
+            )
+            .await;
+        tokio::fs::remove_file(&path).await.unwrap();
+        let result = result.unwrap();
+        assert_eq!(result.manifest_blob_id, blob_id());
+        assert_eq!(result.manifest.size, 10);
+        let sizes: Vec<u64> = result.manifest.chunks.iter().map(|c| c.size).collect();
+        assert_eq!(sizes, [4, 4, 2]);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 4);
+        for (request, chunk) in requests.iter().zip(["0123", "4567", "89"]) {
+            assert!(request.ends_with(chunk.as_bytes()));
+        }
+        let manifest_request = String::from_utf8(requests[3].clone()).unwrap();
+        let manifest_json = &manifest_request[manifest_request.find("\r\n\r\n").unwrap() + 4..];
+        assert_eq!(
+            ChunkManifest::from_bytes(manifest_json.as_bytes()).unwrap(),
+            result.manifest
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_chunked_blob() {
+        let manifest = |hash: &str| {
+            let chunk = format!(r#"{{"blobId":"{}","size":5,"hash":"{hash}"}}"#, blob_id());
+            let body = format!(
+                r#"{{"version":1,"size":10,"chunkSize":5,"hashAlgorithm":"blake2b256","chunks":[{chunk},{chunk}]}}"#
+            );
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            &*response.leak()
+        };
+        let hello_hash = "324dcf027dd4a30a932c441f365a25e86b173defa4b8e58948253471b81b72cf";
+
+        let (url, _) = serve_responses(vec![manifest(hello_hash), OK_HELLO]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+        let mut data = Vec::new();
+        let written = client
+            .read_chunked_blob_to_writer(&blob_id(), &mut data)
+            .await
+            .unwrap();
+        assert_eq!(written, 10);
+        assert_eq!(data, b"hellohello");
+
+        let (url, _) = serve_responses(vec![manifest(&"0".repeat(64)), OK_HELLO]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+        assert!(matches!(
+            client.read_chunked_blob(&blob_id()).await,
+            Err(WalrusError::ManifestMismatch(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_store_blob_from_reader() {
+        let (url, _, requests) = serve_recording(vec![STORED]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+
+        let reader = std::io::Cursor::new(b"reader content and more".to_vec());
+        let result = client
+            .store_blob_from_reader(reader, 14, &StoreOptions::new())
+            .await;
+        assert!(result.is_ok());
+
+        let request = String::from_utf8(requests.lock().unwrap()[0].clone()).unwrap();
+        assert!(request.to_lowercase().contains("content-length: 14"));
+        assert!(request.ends_with("\r\n\r\nreader content"));
+    }
+
+    #[test]
+    fn test_blocking_store_blob_from_reader() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (url, _, requests) = runtime.block_on(serve_recording(vec![STORED]));
+        let client = BlockingWalrusClient::new(&url, &url).unwrap();
+
+        let data = vec![7u8; 200 * 1024];
+        let length = data.len() as u64;
+        let result =
+            client.store_blob_from_reader(std::io::Cursor::new(data), length, &StoreOptions::new());
+        assert!(result.is_ok());
+
+        let request = requests.lock().unwrap()[0].clone();
+        assert!(request.ends_with(&[7u8; 1024]));
+        assert!(request.len() > 200 * 1024);
+    }
+
+    #[test]
+    fn test_store_options_validation() {
+        assert!(StoreOptions::new().validate().is_ok());
+        assert!(
+            StoreOptions::new()
+                .epochs(3)
+                .deletable(true)
+                .send_object_to(
+                    "0x6d1e7d3c5f2e8b8a3c4b2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3f2e1d"
+                        .parse()
+                        .unwrap()
+                )
+                .validate()
+                .is_ok()
+        );
+
+        let invalid = [
+            StoreOptions::new().deletable(true).permanent(true),
+            StoreOptions::new().epochs(0),
+        ];
+        for options in invalid {
+            assert!(matches!(
+                options.validate(),
+                Err(WalrusError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_store_options_are_rejected_before_sending() {
+        let (url, served) = serve_responses(vec![STORED]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+
+        let options = StoreOptions::new().deletable(true).permanent(true);
+        let result = client.store_blob(b"data".to_vec(), &options).await;
+        assert!(matches!(result, Err(WalrusError::InvalidParameter(_))));
+        assert_eq!(served.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_blob_store_result_variants() {
+        let parse = |json: &str| serde_json::from_str::<BlobStoreResult>(json).unwrap();
+        let object_id = format!("0x{}", "ab".repeat(32));
+
+        let certified = parse(&format!(
+            r#"{{"alreadyCertified":{{"blobId":"{}","object":"{object_id}","endEpoch":7}}}}"#,
+            blob_id()
+        ));
+        assert_eq!(certified.blob_id(), Some(&blob_id()));
+        assert_eq!(certified.end_epoch(), Some(7));
+        assert_eq!(certified.object_id().unwrap().to_string(), object_id);
+        assert!(certified.is_stored());
+
+        let invalid = parse(&format!(
+            r#"{{"markedInvalid":{{"blobId":"{}","event":{{"txDigest":"d","eventSeq":"1"}}}}}}"#,
+            blob_id()
+        ));
+        assert!(matches!(invalid, BlobStoreResult::MarkedInvalid(_)));
+        assert_eq!(invalid.end_epoch(), None);
+        assert!(!invalid.is_stored());
+
+        let failed =
+            parse(r#"{"error":{"blobId":null,"failurePhase":"register","errorMsg":"no coins"}}"#);
+        assert!(
+            matches!(&failed, BlobStoreResult::Error(failure) if failure.error_msg == "no coins")
+        );
+        assert_eq!(failed.blob_id(), None);
+
+        let future = r#"{"somethingNew":{"blobId":"x"}}"#;
+        let unknown = parse(future);
+        assert!(matches!(unknown, BlobStoreResult::Unknown(_)));
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), future);
+
+        // A known kind with a malformed body is still an error.
+        assert!(serde_json::from_str::<BlobStoreResult>(r#"{"newlyCreated":{}}"#).is_err());
+    }
+
+    #[test]
+    fn test_blob_id_round_trip() {
+        let id = blob_id();
+        assert_eq!(
+            id.to_string(),
+            "jUtX26C8c9csndZOUSrYmyLKlL_4CPfH1M4fnTI_kjY"
+        );
+        assert_eq!(BlobId::new(*id.as_bytes()), id);
+        assert_eq!(
+            serde_json::to_string(&id).unwrap(),
+            "\"jUtX26C8c9csndZOUSrYmyLKlL_4CPfH1M4fnTI_kjY\""
+        );
+
+        assert!(
+            "jUtX26C8c9csndZOUSrYmyLKlL_4CPfH1M4fnTI_kj"
+                .parse::<BlobId>()
+                .is_err()
+        );
+        assert!(
+            "jUtX26C8c9csndZOUSrYmyLKlL/4CPfH1M4fnTI_kjY"
+                .parse::<BlobId>()
+                .is_err()
+        );
+        assert!("../../v1/api".parse::<BlobId>().is_err());
+    }
+
+    #[test]
+    fn test_hex_ids() {
+        let id: ObjectId = "0x2".parse().unwrap();
+        assert_eq!(
+            id.to_string(),
+            "0x0000000000000000000000000000000000000000000000000000000000000002"
+        );
+        assert_eq!(id.as_bytes()[31], 2);
+        let address: SuiAddress =
+            serde_json::from_str(&serde_json::to_string(&id.to_string()).unwrap()).unwrap();
+        assert_eq!(address.as_bytes(), id.as_bytes());
+
+        for invalid in [
+            "2",
+            "0x",
+            "0xZZ",
+            "0x+f",
+            "0x-1",
+            &format!("0x{}", "1".repeat(65)),
+        ] {
+            assert!(invalid.parse::<ObjectId>().is_err());
+            assert!(matches!(
+                invalid.parse::<SuiAddress>(),
+                Err(WalrusError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_quilt_patch_id() {
+        let id = patch_id();
+        let parsed: QuiltPatchId = id.to_string().parse().unwrap();
+        assert_eq!(parsed, id);
+        assert_eq!(parsed.quilt_id(), &blob_id());
+        assert_eq!(parsed.patch_bytes(), &[1, 0, 0, 1, 0]);
+
+        // A plain Blob ID lacks the bytes locating the patch.
+        assert!(blob_id().to_string().parse::<QuiltPatchId>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_quilt_identifier_is_percent_encoded() {
+        let (url, _, requests) = serve_recording(vec![OK_HELLO]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+
+        client
+            .read_quilt_blob_by_quilt_id_and_identifier(&blob_id(), "dir/file name.txt")
+            .await
+            .unwrap();
+        let request = String::from_utf8(requests.lock().unwrap()[0].clone()).unwrap();
+        assert!(request.starts_with(&format!(
+            "GET /v1/blobs/by-quilt-id/{}/dir%2Ffile%20name.txt ",
+            blob_id()
+        )));
+    }
+
+    #[test]
+    fn test_encoding_config() {
+        let config = EncodingConfig::default();
+        assert_eq!(config.n_shards(), 1000);
+        assert_eq!(config.source_symbols_primary(), 334);
+        assert_eq!(config.source_symbols_secondary(), 667);
+
+        let source_symbols = 334 * 667;
+        assert_eq!(config.symbol_size(0).unwrap(), 2);
+        assert_eq!(config.symbol_size(2 * source_symbols).unwrap(), 2);
+        assert_eq!(config.symbol_size(2 * source_symbols + 1).unwrap(), 4);
+        assert!(config.symbol_size(u64::MAX).is_err());
+
+        // 1000 shards with two 32-byte hashes each, plus the Blob ID, are stored on every shard.
+        let metadata = 1000 * (1000 * 64 + 32);
+        assert_eq!(
+            config.encoded_blob_length(0).unwrap(),
+            1000 * (334 + 667) * 2 + metadata
+        );
+
+        assert!(matches!(
+            EncodingConfig::new(3),
+            Err(WalrusError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_compute_blob_id() {
+        let config = EncodingConfig::new(10).unwrap();
+        let blob_id = config.compute_blob_id(b"hello").unwrap();
+        assert_eq!(config.compute_blob_id(b"hello").unwrap(), blob_id);
+        assert_ne!(config.compute_blob_id(b"hellp").unwrap(), blob_id);
+        // The length is committed to, so trailing zeros matching the padding change the ID.
+        assert_ne!(config.compute_blob_id(b"hello\0").unwrap(), blob_id);
+        assert_ne!(
+            EncodingConfig::new(13)
+                .unwrap()
+                .compute_blob_id(b"hello")
+                .unwrap(),
+            blob_id
+        );
+    }
+
+    /// Deterministic test data: the bytes `0, 1, ..., 250` repeated up to the given length.
+    fn pattern(length: usize) -> Vec<u8> {
+        (0..length).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Data of the Blob ID test vectors: empty, a single byte, several symbols, and one byte more than fits into
+    /// the 2-byte symbols of the 334 x 667 data matrix of 1000 shards.
+    fn blob_id_vector_data() -> [Vec<u8>; 4] {
+        [
+            Vec::new(),
+            vec![0x2a],
+            pattern(1000),
+            pattern(2 * 334 * 667 + 1),
+        ]
+    }
+
+    /// Blob IDs of [`blob_id_vector_data`] for 1000 shards, as computed by this crate.
+    ///
+    /// They are regression vectors, not confirmed by Walrus: they only detect changes of the local encoding.
+    /// `test_compute_blob_id_matches_testnet` stores the same data on Walrus Testnet to compare them with the
+    /// IDs Walrus assigns, and must be run whenever they change.
+    const BLOB_ID_VECTORS: [&str; 4] = [
+        "3GPQL3HZNnFhN_F7l5Aa-X1VOtAKwIsg9zuWk8R81v4",
+        "0niAAmr9-Q_YFFeP-P6wFZtbwUhk-b7hPwAfCPvW2VE",
+        "T5mgHae73yglKnNimxLl3kDX5oyiVh__vYGufQuqZqs",
+        "jl2QwMMLrLNzDZ8Gc-JX-McCxhc62pwf1AiH3OU06JU",
+    ];
+
+    #[test]
+    fn test_compute_blob_id_vectors() {
+        let config = EncodingConfig::default();
+        for (data, expected) in blob_id_vector_data().iter().zip(BLOB_ID_VECTORS) {
+            assert_eq!(
+                config.compute_blob_id(data).unwrap().to_string(),
+                expected,
+                "blob of {} bytes",
+                data.len()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verified_reads() {
+        let (url, _) = serve_responses(vec![OK_HELLO]).await;
+        let encoding_config = EncodingConfig::new(10).unwrap();
+        let client = WalrusClient::builder(&url, &url)
+            .encoding_config(encoding_config)
+            .verify_reads(true)
+            .build()
+            .unwrap();
+        assert!(client.verifies_reads());
+
+        let hello_id = encoding_config.compute_blob_id(b"hello").unwrap();
+        assert_eq!(client.read_blob_by_id(&hello_id).await.unwrap(), b"hello");
+        let stream = client.read_blob_stream_by_id(&hello_id).await.unwrap();
+        assert_eq!(stream.collect_bytes().await.unwrap(), b"hello");
+
+        match client.read_blob_by_id(&blob_id()).await {
+            Err(WalrusError::BlobIdMismatch { expected, actual }) => {
+                assert_eq!(expected, blob_id());
+                assert_eq!(actual, hello_id);
+            }
+            other => panic!("Expected BlobIdMismatch, got {other:?}"),
+        }
+        assert!(matches!(
+            client.read_blob_stream_by_id(&blob_id()).await,
+            Err(WalrusError::BlobIdMismatch { .. })
+        ));
+    }
+
+    /// Checks the regression vectors of locally computed Blob IDs against the ones assigned by Walrus Testnet.
+    #[tokio::test]
+    #[ignore = "stores data on Walrus Testnet"]
+    async fn test_compute_blob_id_matches_testnet() {
+        let client = WalrusClient::new(
+            "https://aggregator.testnet.walrus.atalma.io",
+            "https://publisher.walrus-01.tududes.com",
+        )
+        .unwrap();
+        for (data, expected) in blob_id_vector_data().into_iter().zip(BLOB_ID_VECTORS) {
+            let length = data.len();
+            let result = client
+                .store_blob(data, &StoreOptions::new().epochs(1))
+                .await
+                .unwrap();
+            assert_eq!(
+                result.blob_id().map(ToString::to_string).as_deref(),
+                Some(expected),
+                "blob of {length} bytes"
+            );
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_is_bounded() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300))
+            .jitter(0.0);
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(5), Duration::from_millis(300));
+
+        let jittered = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .jitter(1.0);
+        assert!(jittered.backoff(1) <= Duration::from_millis(100));
+    }
+
     #[test]
     fn test_blocking_walrus_client_new() {
         let aggregator_url = "https://aggregator.testnet.walrus.atalma.io";
         let publisher_url = "https://publisher.walrus-01.tududes.com";
-        let _client = BlockingWalrusClient::new(aggregator_url, publisher_url).unwrap();
         // We can't directly access the URLs in the blocking client,
         // but we can check that it was created successfully.
         // To properly test, we would need to mock the async client's methods
         // or expose the URLs, but for now, we'll just check for creation.
-        assert!(true);
+        let _client = BlockingWalrusClient::new(aggregator_url, publisher_url).unwrap();
     }
 
     #[test]
@@ -58,10 +1108,695 @@ mod tests {
 
     #[test]
     fn test_get_blob_metadata() {
-        let aggregator_url = "https://aggregator.testnet.walrus.atalma.io";
-        let publisher_url = "https://publisher.walrus-01.tududes.com";
-        let client = BlockingWalrusClient::new(aggregator_url, publisher_url).unwrap();
-        let metadata = client.get_blob_metadata("jUtX26C8c9csndZOUSrYmyLKlL_4CPfH1M4fnTI_kjY");
-        assert!(metadata.is_ok());
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.blocking_client().unwrap();
+        let blob_id = server.insert_blob(&b"hello"[..]).unwrap();
+
+        let metadata = client.get_blob_metadata(&blob_id).unwrap();
+        assert_eq!(metadata.content_length, 5);
+        assert_eq!(metadata.content_type, "application/octet-stream");
+        assert!(
+            client
+                .get_blob_metadata(&self::blob_id())
+                .unwrap_err()
+                .api_error()
+                .is_some_and(ApiError::is_not_found)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mock_server_round_trip() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.client_builder().verify_reads(true).build().unwrap();
+
+        let result = client
+            .store_blob(b"hello walrus".to_vec(), &StoreOptions::new().epochs(3))
+            .await
+            .unwrap();
+        assert_eq!(result.end_epoch(), Some(4));
+        let blob_id = *result.blob_id().unwrap();
+        let object_id = *result.object_id().unwrap();
+        assert_eq!(
+            blob_id,
+            server
+                .encoding_config()
+                .compute_blob_id(b"hello walrus")
+                .unwrap()
+        );
+        assert_eq!(
+            client.read_blob_by_id(&blob_id).await.unwrap(),
+            b"hello walrus"
+        );
+        assert_eq!(
+            client.read_blob_by_object_id(&object_id).await.unwrap(),
+            b"hello walrus"
+        );
+        assert_eq!(
+            client.read_blob_range(&blob_id, 6..).await.unwrap(),
+            b"walrus"
+        );
+
+        let quilt = client
+            .store_quilt(
+                vec![
+                    ("a.txt", b"first".to_vec()),
+                    ("b c.txt", b"second".to_vec()),
+                ],
+                None,
+                &StoreOptions::new(),
+            )
+            .await
+            .unwrap();
+        let patch = &quilt.stored_quilt_blobs[1];
+        assert_eq!(patch.identifier, "b c.txt");
+        assert_eq!(
+            client
+                .read_quilt_blob_by_patch_id(&patch.quilt_patch_id)
+                .await
+                .unwrap(),
+            b"second"
+        );
+        assert_eq!(
+            client
+                .read_quilt_blob_by_quilt_id_and_identifier(
+                    patch.quilt_patch_id.quilt_id(),
+                    "a.txt"
+                )
+                .await
+                .unwrap(),
+            b"first"
+        );
+        assert_eq!(server.blob_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_network_info() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.client().unwrap();
+        let info = client.network_info().await.unwrap();
+        assert_eq!(info, *server.network_info());
+        assert_eq!(info.encoding_config().unwrap(), *server.encoding_config());
+        assert!(
+            client.api_spec().await.unwrap()["paths"]
+                .get("/v1/blobs")
+                .is_some()
+        );
+        assert!(client.publisher_api_spec().await.unwrap()["paths"].is_object());
+
+        // Walrus services do not report network info themselves, so it needs a configured endpoint.
+        let unconfigured = WalrusClient::new(server.url(), server.url()).unwrap();
+        assert!(matches!(
+            unconfigured.network_info().await,
+            Err(WalrusError::InvalidParameter(_))
+        ));
+
+        let start = info.current_epoch_start.unwrap();
+        let day = Duration::from_secs(24 * 60 * 60);
+        assert_eq!(
+            info.expiry_time(info.current_epoch + 3),
+            Some(start + 3 * day)
+        );
+        assert_eq!(info.epoch_start(info.current_epoch - 1), Some(start - day));
+        assert_eq!(info.epochs_for(Duration::from_secs(1)), 1);
+        assert_eq!(info.epochs_for(day * 2), 3);
+        assert_eq!(info.epochs_for(day * 1000), info.max_epochs_ahead);
+
+        let info: NetworkInfo = serde_json::from_str(
+            r#"{"epochInfo":{"currentEpoch":7,"startOfCurrentEpoch":{"Message":"unknown"},
+                "epochDuration":{"secs":1209600,"nanos":0},"maxEpochsAhead":53},
+                "storageInfo":{"nNodes":100,"nShards":1000},
+                "sizeInfo":{"storageUnitSize":1048576,"maxBlobSize":13958643712}}"#,
+        )
+        .unwrap();
+        assert_eq!(info.current_epoch, 7);
+        assert_eq!(info.epoch_duration, Duration::from_secs(14 * 24 * 60 * 60));
+        assert_eq!(info.n_shards, 1000);
+        assert_eq!(info.expiry_time(10), None);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_store_cost() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.client().unwrap();
+        let estimate = client.estimate_store_cost(12, 3).await.unwrap();
+        assert_eq!(estimate.storage_units, 1);
+        assert_eq!(
+            estimate.total_cost().unwrap(),
+            3 * MockWalrusServer::PRICES.storage_price_per_unit_size
+                + MockWalrusServer::PRICES.write_price_per_unit_size
+        );
+
+        let result = client
+            .store_blob(b"hello walrus".to_vec(), &StoreOptions::new().epochs(3))
+            .await
+            .unwrap();
+        let BlobStoreResult::NewlyCreated(created) = &result else {
+            panic!("unexpected store result {result:?}");
+        };
+        assert_eq!(
+            created.blob_object.storage.storage_size,
+            estimate.encoded_length
+        );
+        assert_eq!(result.cost(), Some(estimate.total_cost().unwrap()));
+
+        for epochs in [0, MockWalrusServer::MAX_EPOCHS_AHEAD + 1] {
+            assert!(matches!(
+                client.estimate_store_cost(12, epochs).await,
+                Err(WalrusError::InvalidParameter(_))
+            ));
+        }
+        let mut info = server.network_info().clone();
+        info.prices = None;
+        assert!(info.estimate_store_cost(12, 3).is_err());
+
+        let estimate: StoreCostEstimate = serde_json::from_value(serde_json::json!({
+            "encodedLength": 1,
+            "storageUnits": 1,
+            "epochs": 1,
+            "storageCost": u64::MAX,
+            "writeCost": u64::MAX,
+        }))
+        .unwrap();
+        assert!(matches!(
+            estimate.total_cost(),
+            Err(WalrusError::InvalidParameter(_))
+        ));
+        let prices = StoragePrices {
+            storage_unit_size: 1,
+            storage_price_per_unit_size: u64::MAX / 2,
+            write_price_per_unit_size: u64::MAX / 2 + 2,
+        };
+        assert!(matches!(
+            prices.estimate(1, 1),
+            Err(WalrusError::InvalidParameter(_))
+        ));
+        info.current_epoch = u64::MAX;
+        assert_eq!(info.epochs_for(Duration::from_secs(1)), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mock_server_faults() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server
+            .client_builder()
+            .retry_policy(fast_retries(3))
+            .build()
+            .unwrap();
+        let blob_id = server.insert_blob(vec![1u8; 1000]).unwrap();
+
+        server.inject_fault(Fault::Status(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+        server.inject_fault(Fault::Latency(Duration::from_millis(10)));
+        assert_eq!(client.read_blob_by_id(&blob_id).await.unwrap().len(), 1000);
+        assert_eq!(server.request_count(), 2);
+
+        server.inject_fault(Fault::TruncatedBody);
+        assert!(matches!(
+            client.read_blob_by_id(&blob_id).await,
+            Err(WalrusError::ParseError(_))
+        ));
+        server.inject_fault(Fault::Status(reqwest::StatusCode::BAD_GATEWAY));
+        let error = client
+            .store_blob(b"data".to_vec(), &StoreOptions::new())
+            .await
+            .unwrap_err();
+        assert_eq!(error.api_error().unwrap().reason(), Some("INJECTED_FAULT"));
+    }
+
+    #[tokio::test]
+    async fn test_list_quilt_patches() {
+        const PATCHES: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 110\r\nConnection: close\r\n\r\n[{\"identifier\":\"a.txt\",\"patchId\":\"jUtX26C8c9csndZOUSrYmyLKlL_4CPfH1M4fnTI_kjYBAAABAA\",\"tags\":{\"kind\":\"text\"}}]";
+        let (url, _, requests) = serve_recording(vec![PATCHES]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+
+        let patches = client.list_quilt_patches(&blob_id()).await.unwrap();
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].identifier, "a.txt");
+        assert_eq!(patches[0].quilt_patch_id, patch_id());
+        assert_eq!(patches[0].tags["kind"], "text");
+        assert_eq!(patches[0].size, None);
+        let request = String::from_utf8(requests.lock().unwrap()[0].clone()).unwrap();
+        assert!(request.starts_with(&format!("GET /v1/quilts/{}/patches ", blob_id())));
+    }
+
+    #[tokio::test]
+    async fn test_read_quilt_patch_attributes() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server
+            .client_builder()
+            .quilt_tag_headers(["Author"])
+            .build()
+            .unwrap();
+        let quilt = client
+            .store_quilt(
+                vec![("notes.txt", b"notes".to_vec())],
+                Some(vec![QuiltMetadata {
+                    identifier: "notes.txt".to_string(),
+                    tags: [
+                        ("author".to_string(), "alice".to_string()),
+                        ("content-type".to_string(), "text/plain".to_string()),
+                    ]
+                    .into(),
+                }]),
+                &StoreOptions::new(),
+            )
+            .await
+            .unwrap();
+        let patch_id = &quilt.stored_quilt_blobs[0].quilt_patch_id;
+
+        let patch = client.read_quilt_patch_by_patch_id(patch_id).await.unwrap();
+        assert_eq!(patch.data, b"notes");
+        assert_eq!(patch.identifier.as_deref(), Some("notes.txt"));
+        assert_eq!(patch.content_type.as_deref(), Some("text/plain"));
+        assert_eq!(
+            patch.tags,
+            [("author".to_string(), "alice".to_string())].into()
+        );
+        let by_identifier = client
+            .read_quilt_patch_by_quilt_id_and_identifier(patch_id.quilt_id(), "notes.txt")
+            .await
+            .unwrap();
+        assert_eq!(by_identifier.tags, patch.tags);
+        assert_eq!(by_identifier.content_type, patch.content_type);
+    }
+
+    #[tokio::test]
+    async fn test_read_quilt_patch_ignores_transport_headers() {
+        const PATCH: &str = "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\nX-Quilt-Patch-Identifier: notes.txt\r\nContent-Disposition: inline\r\nDate: Thu, 01 Jan 2026 00:00:00 GMT\r\nServer: cloudflare\r\nVia: 1.1 varnish\r\nCF-Ray: 8f1a2b3c4d5e6f70-AMS\r\nX-Request-Id: 0b4e8c1f\r\nAccess-Control-Allow-Origin: *\r\nConnection: close\r\n\r\nnotes";
+        let (url, _) = serve_responses(vec![PATCH]).await;
+        let client = WalrusClient::new(&url, &url).unwrap();
+
+        let patch = client
+            .read_quilt_patch_by_patch_id(&patch_id())
+            .await
+            .unwrap();
+        assert_eq!(patch.identifier.as_deref(), Some("notes.txt"));
+        assert_eq!(patch.content_type.as_deref(), Some("text/plain"));
+        assert_eq!(
+            patch.tags,
+            [("content-disposition".to_string(), "inline".to_string())].into()
+        );
+        assert_eq!(patch.headers["x-request-id"], "0b4e8c1f");
+    }
+
+    #[tokio::test]
+    async fn test_store_quilt_from_dir() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.client().unwrap();
+        let dir = std::env::temp_dir().join(format!("walrus-rs-dir-{}", std::process::id()));
+        for (path, data) in [
+            ("index.html", "<html>"),
+            ("css/site.css", "body {}"),
+            ("css/site.css.bak", "old"),
+            (".git/HEAD", "ref"),
+            ("readme", "hello"),
+        ] {
+            let path = dir.join(path);
+            tokio::fs::create_dir_all(path.parent().unwrap())
+                .await
+                .unwrap();
+            tokio::fs::write(path, data).await.unwrap();
+        }
+
+        let dir_options = QuiltDirOptions::new()
+            .exclude(".git")
+            .exclude("**/*.bak")
+            .max_files_per_quilt(2);
+        let result = client
+            .store_quilt_from_dir(&dir, &dir_options, &StoreOptions::new())
+            .await;
+        let too_large = client
+            .store_quilt_from_dir(
+                &dir,
+                &QuiltDirOptions::new().max_quilt_input_size(4),
+                &StoreOptions::new(),
+            )
+            .await;
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+        assert!(matches!(too_large, Err(WalrusError::InvalidParameter(_))));
+
+        let quilts = result.unwrap();
+        let identifiers: Vec<Vec<&str>> = quilts
+            .iter()
+            .map(|quilt| {
+                quilt
+                    .stored_quilt_blobs
+                    .iter()
+                    .map(|patch| patch.identifier.as_str())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            identifiers,
+            [vec!["css/site.css", "index.html"], vec!["readme"]]
+        );
+
+        let quilt_id = quilts[0].blob_store_result.blob_id().unwrap();
+        let patches = client.list_quilt_patches(quilt_id).await.unwrap();
+        let tags = &patches[1].tags;
+        assert_eq!(tags["content-type"], "text/html");
+        assert_eq!(tags["size"], "6");
+        assert_eq!(tags["hash"].len(), 64);
+        assert!(tags["modified"].parse::<u64>().unwrap() > 0);
+        assert_eq!(
+            client
+                .read_quilt_blob_by_quilt_id_and_identifier(quilt_id, "css/site.css")
+                .await
+                .unwrap(),
+            b"body {}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_store_quilt_from_dir_round_trip() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.client().unwrap();
+        let base =
+            std::env::temp_dir().join(format!("walrus-rs-round-trip-{}", std::process::id()));
+        let source = base.join("source");
+        tokio::fs::create_dir_all(source.join("docs"))
+            .await
+            .unwrap();
+        tokio::fs::write(source.join("docs/a.txt"), "alpha")
+            .await
+            .unwrap();
+        tokio::fs::symlink("docs/a.txt", source.join("link.txt"))
+            .await
+            .unwrap();
+        tokio::fs::symlink(".", source.join("loop")).await.unwrap();
+
+        let store_and_download = |options: QuiltDirOptions, target: &'static str| {
+            let (client, source, target) = (&client, &source, base.join(target));
+            async move {
+                let quilts = client
+                    .store_quilt_from_dir(source, &options, &StoreOptions::new())
+                    .await?;
+                let quilt_id = quilts[0].blob_store_result.blob_id().unwrap();
+                let paths = client.download_quilt_to_dir(quilt_id, &target).await?;
+                Ok::<_, WalrusError>(
+                    paths
+                        .iter()
+                        .map(|path| path.strip_prefix(&target).unwrap().to_path_buf())
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+        let skipped = store_and_download(QuiltDirOptions::new(), "skipped").await;
+        let followed =
+            store_and_download(QuiltDirOptions::new().follow_symlinks(true), "followed").await;
+        let link_contents = tokio::fs::read(base.join("followed/link.txt")).await;
+        tokio::fs::write(source.join("c:d.txt"), "colon")
+            .await
+            .unwrap();
+        let unsafe_name = store_and_download(QuiltDirOptions::new(), "unsafe").await;
+        tokio::fs::remove_dir_all(&base).await.unwrap();
+
+        assert_eq!(skipped.unwrap(), [std::path::Path::new("docs/a.txt")]);
+        assert_eq!(
+            followed.unwrap(),
+            [
+                std::path::Path::new("docs/a.txt"),
+                std::path::Path::new("link.txt")
+            ]
+        );
+        assert_eq!(link_contents.unwrap(), b"alpha");
+        assert!(matches!(unsafe_name, Err(WalrusError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_download_quilt_to_dir() {
+        let server = MockWalrusServer::start().unwrap();
+        let client = server.client().unwrap();
+        let base = std::env::temp_dir().join(format!("walrus-rs-download-{}", std::process::id()));
+        let source = base.join("source");
+        tokio::fs::create_dir_all(source.join("docs"))
+            .await
+            .unwrap();
+        tokio::fs::write(source.join("docs/a.txt"), "alpha")
+            .await
+            .unwrap();
+        tokio::fs::write(source.join("b.txt"), "beta")
+            .await
+            .unwrap();
+        let quilts = client
+            .store_quilt_from_dir(&source, &QuiltDirOptions::new(), &StoreOptions::new())
+            .await
+            .unwrap();
+        let quilt_id = quilts[0].blob_store_result.blob_id().unwrap();
+
+        let target = base.join("target");
+        let paths = client.download_quilt_to_dir(quilt_id, &target).await;
+        let contents = tokio::fs::read(target.join("docs/a.txt")).await;
+        let modified =
+            |path: &std::path::Path| std::fs::metadata(path).unwrap().modified().unwrap();
+        let mtime_drift =
+            modified(&source.join("b.txt")).duration_since(modified(&target.join("b.txt")));
+
+        let store_one = |identifier: &'static str, tags: Vec<(&'static str, &'static str)>| {
+            let client = &client;
+            async move {
+                let metadata = QuiltMetadata {
+                    identifier: identifier.to_string(),
+                    tags: tags
+                        .into_iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect(),
+                };
+                let quilt = client
+                    .store_quilt(
+                        vec![(identifier, b"data".to_vec())],
+                        Some(vec![metadata]),
+                        &StoreOptions::new(),
+                    )
+                    .await
+                    .unwrap();
+                *quilt.blob_store_result.blob_id().unwrap()
+            }
+        };
+        let escaping = store_one("../evil.txt", vec![]).await;
+        let escaped = client
+            .download_quilt_to_dir(&escaping, base.join("escaping"))
+            .await;
+        let corrupt = store_one("corrupt.txt", vec![("hash", "00")]).await;
+        let mismatched = client
+            .download_quilt_to_dir(&corrupt, base.join("corrupt"))
+            .await;
+        let escaped_exists = base.join("evil.txt").exists();
+        tokio::fs::remove_dir_all(&base).await.unwrap();
+
+        assert_eq!(
+            paths.unwrap(),
+            [target.join("b.txt"), target.join("docs/a.txt")]
+        );
+        assert_eq!(contents.unwrap(), b"alpha");
+        assert!(mtime_drift.unwrap() < Duration::from_secs(1));
+        assert!(matches!(escaped, Err(WalrusError::InvalidParameter(_))));
+        assert!(!escaped_exists);
+        assert!(matches!(
+            mismatched,
+            Err(WalrusError::QuiltPatchMismatch(_))
+        ));
+    }
+
+    /// Exercises a store through the trait object, as application code written against `WalrusStore` would.
+    async fn exercise_store(store: &dyn WalrusStore) {
+        let permanent = StoreOptions::new().permanent(true);
+        let result = store
+            .store_blob(b"hello walrus".to_vec(), &permanent)
+            .await
+            .unwrap();
+        assert!(result.is_stored());
+        let blob_id = *result.blob_id().unwrap();
+        assert_eq!(
+            store.read_blob_by_id(&blob_id).await.unwrap(),
+            b"hello walrus"
+        );
+        assert_eq!(
+            store
+                .read_blob_by_object_id(result.object_id().unwrap())
+                .await
+                .unwrap(),
+            b"hello walrus"
+        );
+        assert_eq!(
+            store
+                .get_blob_metadata(&blob_id)
+                .await
+                .unwrap()
+                .content_length,
+            12
+        );
+        let again = store
+            .store_blob(b"hello walrus".to_vec(), &permanent)
+            .await
+            .unwrap();
+        assert!(matches!(again, BlobStoreResult::AlreadyCertified(_)));
+        assert_eq!(again.blob_id(), Some(&blob_id));
+        assert_eq!(again.cost(), None);
+
+        let quilt = store
+            .store_quilt(
+                vec![("a.txt", b"first".to_vec()), ("b.txt", b"second".to_vec())],
+                Some(vec![QuiltMetadata {
+                    identifier: "b.txt".to_string(),
+                    tags: [("kind".to_string(), "text".to_string())].into(),
+                }]),
+                &StoreOptions::new(),
+            )
+            .await
+            .unwrap();
+        let patch = &quilt.stored_quilt_blobs[1];
+        let listed = store
+            .list_quilt_patches(patch.quilt_patch_id.quilt_id())
+            .await
+            .unwrap();
+        assert_eq!(listed[1].identifier, "b.txt");
+        assert_eq!(listed[1].quilt_patch_id, patch.quilt_patch_id);
+        assert_eq!(listed[1].size, Some(6));
+        assert_eq!(listed[1].tags["kind"], "text");
+        assert_eq!(
+            store
+                .read_quilt_blob_by_patch_id(&patch.quilt_patch_id)
+                .await
+                .unwrap(),
+            b"second"
+        );
+        assert_eq!(
+            store
+                .read_quilt_blob_by_quilt_id_and_identifier(
+                    patch.quilt_patch_id.quilt_id(),
+                    "a.txt"
+                )
+                .await
+                .unwrap(),
+            b"first"
+        );
+
+        let missing = store.read_blob_by_id(&self::blob_id()).await.unwrap_err();
+        assert!(missing.api_error().is_some_and(ApiError::is_not_found));
+        assert!(matches!(
+            store
+                .store_quilt(
+                    vec![("a", vec![1]), ("a", vec![2])],
+                    None,
+                    &StoreOptions::new()
+                )
+                .await,
+            Err(WalrusError::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_memory_store() {
+        let store = MemoryStore::new();
+        exercise_store(&store).await;
+        assert_eq!(store.blob_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_file_system_store() {
+        let root = std::env::temp_dir().join(format!("walrus-rs-store-{}", std::process::id()));
+        exercise_store(&FileSystemStore::open(&root).unwrap()).await;
+
+        // A reopened store sees the Blobs stored before.
+        let store = FileSystemStore::open(&root).unwrap();
+        let blob_id = store
+            .encoding_config()
+            .compute_blob_id(b"hello walrus")
+            .unwrap();
+        let data = store.read_blob_by_id(&blob_id).await;
+        tokio::fs::remove_dir_all(&root).await.unwrap();
+        assert_eq!(data.unwrap(), b"hello walrus");
+    }
+
+    /// Runs the `walrus-rs` tool against a server, ignoring any configuration of the environment.
+    fn walrus_rs(server: &MockWalrusServer, args: &[&str], stdin: &[u8]) -> std::process::Output {
+        let mut child = std::process::Command::new(env!("CARGO_BIN_EXE_walrus-rs"))
+            .args(["--aggregator", server.url(), "--publisher", server.url()])
+            .args(["--network-info-path", MockWalrusServer::NETWORK_INFO_PATH])
+            .args(args)
+            .env(
+                "XDG_CONFIG_HOME",
+                std::env::temp_dir().join("walrus-rs-no-config"),
+            )
+            .env_remove("WALRUS_RS_CONFIG")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+        std::io::Write::write_all(&mut child.stdin.take().unwrap(), stdin).unwrap();
+        child.wait_with_output().unwrap()
+    }
+
+    #[test]
+    fn test_cli() {
+        let server = MockWalrusServer::start().unwrap();
+
+        let stored = walrus_rs(
+            &server,
+            &["--format", "json", "store", "--epochs", "2"],
+            b"piped data",
+        );
+        assert!(stored.status.success());
+        let result: serde_json::Value = serde_json::from_slice(&stored.stdout).unwrap();
+        let blob_id = result["newlyCreated"]["blobObject"]["blobId"]
+            .as_str()
+            .unwrap();
+        assert_eq!(
+            result["newlyCreated"]["blobObject"]["storage"]["endEpoch"],
+            3
+        );
+
+        let read = walrus_rs(&server, &["cat", blob_id, blob_id], b"");
+        assert_eq!(read.stdout, b"piped datapiped data");
+        let head = walrus_rs(&server, &["metadata", blob_id], b"");
+        assert!(
+            String::from_utf8(head.stdout)
+                .unwrap()
+                .contains("Content-Length: 10")
+        );
+
+        let missing = walrus_rs(&server, &["read", &self::blob_id().to_string()], b"");
+        assert!(!missing.status.success());
+        assert!(
+            String::from_utf8(missing.stderr)
+                .unwrap()
+                .starts_with("error: API error")
+        );
+
+        let dir = std::env::temp_dir().join(format!("walrus-rs-cli-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("notes.txt");
+        std::fs::write(&file, b"quilt file").unwrap();
+        let quilt = walrus_rs(
+            &server,
+            &[
+                "store-quilt",
+                file.to_str().unwrap(),
+                "--tag",
+                "notes.txt:kind=text",
+            ],
+            b"",
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        let quilt = String::from_utf8(quilt.stdout).unwrap();
+        let quilt_id = quilt
+            .lines()
+            .find_map(|line| line.strip_prefix("Quilt ID: "))
+            .unwrap();
+        let read = walrus_rs(&server, &["read-quilt", quilt_id, "notes.txt"], b"");
+        assert_eq!(read.stdout, b"quilt file");
+        let listed = walrus_rs(&server, &["list-quilt", quilt_id], b"");
+        let listed = String::from_utf8(listed.stdout).unwrap();
+        assert!(listed.starts_with("notes.txt\n"));
+        assert!(listed.contains("  Tag: kind=text\n"));
+
+        let info = walrus_rs(&server, &["info"], b"");
+        assert!(
+            String::from_utf8(info.stdout)
+                .unwrap()
+                .contains("Shards: 10\n")
+        );
     }
 }